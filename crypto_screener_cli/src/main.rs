@@ -0,0 +1,251 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # WBTek Crypto Screener Command Line
+//!
+//! This binary runs the screener once from a terminal or a cron job: it fetches the
+//! tickers, applies the same filter and sort as the web app, and prints an aligned table,
+//! CSV or JSON to stdout. With `--tui` it instead runs an interactive, auto-refreshing
+//! terminal version of the web app's table (see `tui`). The data model, filtering,
+//! sorting and formatting all come from `crypto_screener_core`, so a screen gives the
+//! same rows everywhere.
+//!
+//! ## Example
+//!
+//! ```text
+//! crypto-screener --filter "volume24>1000000" --sort percent_change_24h --limit 20
+//! crypto-screener --format csv --columns symbol,price_usd,volume24 > tickers.csv
+//! crypto-screener --url http://localhost:8080/api/tickers/
+//! crypto-screener --snapshot tickers-2024-10-31.json --format csv
+//! curl -s https://api.coinlore.net/api/tickers/ | crypto-screener --snapshot -
+//! crypto-screener --tui --filter "volume24>1000000"
+//! ```
+
+mod table;
+mod tui;
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use clap::{Parser, ValueEnum};
+use serde_json::Value;
+use crypto_screener_core::columns::{column, Column, COLUMNS, HIDDEN_BY_DEFAULT};
+use crypto_screener_core::cryptodata::{parse_tickers, CryptoData, DEFAULT_TICKERS_URL};
+use crypto_screener_core::currency::Quote;
+use crypto_screener_core::export::ExportFormat;
+use crypto_screener_core::filter::Filter;
+use crypto_screener_core::snapshot::{decode_data_url, is_data_url, parse_snapshot};
+use crypto_screener_core::sort::{default_ascending, sort_data};
+
+/// Columns printed unless `--columns` is given. The technical indicators are left out, as
+/// they need history recorded across refreshes and a single run has none.
+const DEFAULT_COLUMNS: [&str; 7] = [
+    "symbol",
+    "name",
+    "price_usd",
+    "percent_change_1h",
+    "percent_change_24h",
+    "percent_change_7d",
+    "volume24",
+];
+
+/// Output formats for the selected rows.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum OutputFormat {
+    /// An aligned table of display values, as in the web app.
+    Table,
+
+    /// CSV with raw values, as exported by the web app.
+    Csv,
+
+    /// A JSON array of objects with raw values, as exported by the web app.
+    Json,
+}
+
+/// Command-line options.
+#[derive(Parser, Debug)]
+#[command(name = "crypto-screener", version, about = "Screens cryptocurrency tickers from the command line.")]
+struct Args {
+    /// Ticker endpoint returning Coinlore-format JSON; point it at a mock server for
+    /// testing. A data: URL is decoded in place.
+    #[arg(long, default_value = DEFAULT_TICKERS_URL)]
+    url: String,
+
+    /// Read a recorded ticker response from this file, or from stdin for "-", instead of
+    /// fetching.
+    #[arg(long, conflicts_with = "url")]
+    snapshot: Option<PathBuf>,
+
+    /// Column to sort by.
+    #[arg(long, default_value = "volume24")]
+    sort: String,
+
+    /// Sort ascending. By default text columns sort ascending and numeric columns descending.
+    #[arg(long, conflicts_with = "desc")]
+    asc: bool,
+
+    /// Sort descending.
+    #[arg(long)]
+    desc: bool,
+
+    /// Filter query, as typed in the web app, e.g. "btc rsi_14>70 volume24>1000000".
+    #[arg(long, default_value = "")]
+    filter: String,
+
+    /// Comma-separated column keys to print, in order.
+    #[arg(long, value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// Print at most this many rows.
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Run the interactive terminal UI instead of printing once.
+    #[arg(long, conflicts_with_all = ["format", "limit"])]
+    tui: bool,
+
+    /// Seconds between refreshes in the terminal UI.
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    refresh: u64,
+}
+
+/// Where tickers are read from.
+#[derive(Clone, Debug, PartialEq)]
+enum Source {
+    /// A ticker endpoint, or an inline `data:` URL.
+    Url(String),
+
+    /// A recorded response in a file.
+    File(PathBuf),
+
+    /// A recorded response piped to stdin.
+    Stdin,
+}
+
+impl Source {
+    /// Reads and parses the tickers. Every source goes through the same `parse_tickers`
+    /// as the web app's `fetch_data`.
+    fn load(&self) -> Result<Vec<CryptoData>, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            Source::Url(url) if is_data_url(url) => Ok(parse_snapshot(&decode_data_url(url)?)?),
+            Source::Url(url) => Ok(fetch_tickers(url)?),
+            Source::File(path) => {
+                let text = fs::read_to_string(path)
+                    .map_err(|err| format!("can't read {}: {}", path.display(), err))?;
+                Ok(parse_snapshot(&text)?)
+            }
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(parse_snapshot(&text)?)
+            }
+        }
+    }
+}
+
+impl Args {
+    /// Returns the source chosen by `--snapshot` or `--url`.
+    fn source(&self) -> Source {
+        match &self.snapshot {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path.clone()),
+            None => Source::Url(self.url.clone()),
+        }
+    }
+
+    /// Returns the requested sort direction, or the sort column's default direction.
+    fn ascending(&self) -> bool {
+        if self.asc || self.desc { self.asc } else { default_ascending(&self.sort) }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("crypto-screener: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Fetches, screens and prints the tickers as described by `args`.
+fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    if column(&args.sort).is_none() {
+        return Err(format!("unknown sort column \"{}\"", args.sort).into());
+    }
+    if args.tui {
+        if args.source() == Source::Stdin {
+            return Err("--snapshot - reads stdin, which --tui needs for the keyboard".into());
+        }
+        // As in the web app, every column is shown, since refreshes build up the history
+        // the technical indicators need.
+        let defaults: Vec<&str> = COLUMNS.iter()
+            .map(|c| c.key)
+            .filter(|key| !HIDDEN_BY_DEFAULT.contains(key))
+            .collect();
+        return tui::run(args, selected_columns(&args.columns, &defaults)?);
+    }
+    let columns = selected_columns(&args.columns, &DEFAULT_COLUMNS)?;
+
+    let mut data = args.source().load().map_err(|err| err as Box<dyn std::error::Error>)?;
+    let filter = Filter::parse(&args.filter);
+    data.retain(|item| filter.matches(item));
+    sort_data(&mut data, &Some(args.sort.clone()), args.ascending());
+    data.truncate(args.limit.unwrap_or(data.len()));
+
+    let keys: Vec<&str> = columns.iter().map(|c| c.key).collect();
+    let output = match args.format {
+        OutputFormat::Table => table::render(&data, &columns, &Quote::default()),
+        OutputFormat::Csv => ExportFormat::Csv.render(data.iter(), &keys),
+        OutputFormat::Json => ExportFormat::Json.render(data.iter(), &keys) + "\n",
+    };
+    match io::stdout().lock().write_all(output.as_bytes()) {
+        // A closed pipe, as with `| head`, is not an error.
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Resolves the `--columns` keys, or the `defaults` if none were given.
+fn selected_columns(keys: &[String], defaults: &[&str]) -> Result<Vec<&'static Column>, String> {
+    if keys.is_empty() {
+        return Ok(defaults.iter().filter_map(|key| column(key)).collect());
+    }
+    keys.iter()
+        .map(|key| column(key.trim()).ok_or_else(|| format!("unknown column \"{}\"", key)))
+        .collect()
+}
+
+/// Fetches tickers from `url` and parses them exactly as the web app's `fetch_data` does.
+fn fetch_tickers(url: &str) -> Result<Vec<CryptoData>, reqwest::Error> {
+    let payload = reqwest::blocking::get(url)?.error_for_status()?.json::<Value>()?;
+    Ok(parse_tickers(&payload))
+}
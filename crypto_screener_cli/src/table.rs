@@ -0,0 +1,112 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Table Module
+//!
+//! This module prints rows as a plain-text table for the terminal. Cells hold the same
+//! display values as the web app's table, numeric columns are right-aligned, and every
+//! column is as wide as its widest cell.
+
+use crypto_screener_core::columns::Column;
+use crypto_screener_core::cryptodata::{CryptoData, NUMERIC_COLUMNS};
+use crypto_screener_core::currency::Quote;
+use crypto_screener_core::i18n::Language;
+use crypto_screener_core::numformat::NumberLocale;
+
+/// Spaces between adjacent columns.
+const GAP: &str = "  ";
+
+/// Renders `rows` as an aligned table of `columns`, with a header line and a rule below it.
+///
+/// # Example
+///
+/// ```rust
+/// let text = render(&data, &[column("symbol").unwrap()], &Quote::default());
+/// ```
+pub fn render(rows: &[CryptoData], columns: &[&Column], quote: &Quote) -> String {
+    let header: Vec<String> = columns.iter().map(|c| c.title(quote, Language::En)).collect();
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|item| columns.iter().map(|c| (c.value)(item, quote, &NumberLocale::default())).collect())
+        .collect();
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            cells.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut text = format_line(&header, columns, &widths);
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    text.push_str(&rule.join(GAP));
+    text.push('\n');
+    for row in &cells {
+        text.push_str(&format_line(row, columns, &widths));
+    }
+    text
+}
+
+/// Pads each cell to its column's width, right-aligning numeric columns, and trims the
+/// trailing space of the line.
+fn format_line(cells: &[String], columns: &[&Column], widths: &[usize]) -> String {
+    let padded: Vec<String> = cells
+        .iter()
+        .zip(columns.iter().zip(widths))
+        .map(|(cell, (column, width))| {
+            if NUMERIC_COLUMNS.contains(&column.key) {
+                format!("{:>width$}", cell, width = width)
+            } else {
+                format!("{:<width$}", cell, width = width)
+            }
+        })
+        .collect();
+    let mut line = padded.join(GAP).trim_end().to_string();
+    line.push('\n');
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_screener_core::columns::column;
+
+    #[test]
+    fn aligns_text_left_and_numbers_right() {
+        let rows = [
+            CryptoData { symbol: Some("BTC".to_string()), volume24: Some(1.5e9), ..CryptoData::default() },
+            CryptoData { symbol: Some("DOGE".to_string()), volume24: Some(2.0e6), ..CryptoData::default() },
+        ];
+        let columns = [column("symbol").unwrap(), column("volume24").unwrap()];
+        let text = render(&rows, &columns, &Quote::default());
+        assert_eq!(text, "\
+Symbol  Volume (USD)
+------  ------------
+BTC             1.5B
+DOGE            2.0M
+");
+    }
+}
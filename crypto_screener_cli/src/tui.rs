@@ -0,0 +1,380 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Terminal UI Module
+//!
+//! This module runs the screener as an interactive table in the terminal, built on
+//! ratatui. It follows the web app's `Model`: the data is refreshed periodically (a
+//! snapshot file is simply read again), each refresh is recorded in a `History` so the
+//! technical indicators fill in, rows are filtered and sorted with the shared core logic,
+//! and a keyboard cursor highlights cells just as the web app's keyboard shortcuts do.
+//!
+//! ## Keys
+//!
+//! - Arrow keys move the cell cursor; Page Up/Down move by a screen.
+//! - Space highlights or unhighlights the cell under the cursor.
+//! - `1`–`9` and `0` sort by the 1st–9th or 10th column; again to reverse.
+//! - `r` refreshes now; `q` or Escape quits.
+
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use crypto_screener_core::columns::Column;
+use crypto_screener_core::cryptodata::{CryptoData, NUMERIC_COLUMNS};
+use crypto_screener_core::currency::Quote;
+use crypto_screener_core::filter::Filter;
+use crypto_screener_core::history::History;
+use crypto_screener_core::i18n::Language;
+use crypto_screener_core::numformat::NumberLocale;
+use crypto_screener_core::selection::toggle_cell_selection;
+use crypto_screener_core::sort::{select_sort_column, sort_data};
+use crate::Args;
+
+/// How long to wait for a key press before checking for fetched data, in milliseconds.
+const POLL_INTERVAL_MS: u64 = 250;
+
+/// Rows moved by Page Up and Page Down.
+const PAGE_ROWS: i32 = 20;
+
+/// Result of a background fetch, with the error as text.
+type FetchResult = Result<Vec<CryptoData>, String>;
+
+/// Things the user can do, analogous to the web app's `Msg`.
+#[derive(Debug, PartialEq)]
+enum Action {
+    /// Moves the cursor by columns and rows.
+    MoveCursor(i32, i32),
+
+    /// Toggles the highlight of the cell under the cursor.
+    ToggleCursorSelection,
+
+    /// Sorts by the visible column at this index.
+    SortColumnAt(usize),
+
+    /// Fetches the data now.
+    Refresh,
+
+    /// Leaves the terminal UI.
+    Quit,
+}
+
+/// State of the terminal UI, analogous to the web app's `Model`.
+struct App {
+    /// Every fetched row, sorted but not filtered.
+    data: Vec<CryptoData>,
+
+    /// Columns shown, in order.
+    columns: Vec<&'static Column>,
+
+    /// The filter applied to `data` when rendering.
+    filter: Filter,
+
+    /// Column currently sorted by.
+    sort_by: Option<String>,
+
+    /// Whether the sort is ascending.
+    sort_asc: bool,
+
+    /// Highlighted cells, as `(id, column)` pairs.
+    selected_cells: HashSet<(String, String)>,
+
+    /// The `(id, column)` of the cell under the cursor, once it has moved.
+    cursor: Option<(String, String)>,
+
+    /// Samples recorded on every refresh, used to compute indicators.
+    history: History,
+
+    /// The last fetch error, shown in the status line until a fetch succeeds.
+    error_message: Option<String>,
+
+    /// When data was last loaded, in seconds since the epoch.
+    last_updated: Option<u64>,
+
+    /// Set once the user asks to quit.
+    quit: bool,
+}
+
+impl App {
+    /// Creates the state for `columns`, sorted and filtered as `args` requests.
+    fn new(args: &Args, columns: Vec<&'static Column>) -> Self {
+        App {
+            data: Vec::new(),
+            columns,
+            filter: Filter::parse(&args.filter),
+            sort_by: Some(args.sort.clone()),
+            sort_asc: args.ascending(),
+            selected_cells: HashSet::new(),
+            cursor: None,
+            history: History::default(),
+            error_message: None,
+            last_updated: None,
+            quit: false,
+        }
+    }
+
+    /// Returns the rows that pass the filter, in sort order.
+    fn visible_rows(&self) -> Vec<&CryptoData> {
+        self.data.iter().filter(|item| self.filter.matches(item)).collect()
+    }
+
+    /// Returns the cursor as `(row, column)` indices into the visible rows and columns.
+    fn cursor_position(&self, rows: &[&CryptoData]) -> Option<(usize, usize)> {
+        let (id, key) = self.cursor.as_ref()?;
+        let row = rows.iter().position(|item| item.symbol.as_ref() == Some(id))?;
+        let column = self.columns.iter().position(|c| c.key == key)?;
+        Some((row, column))
+    }
+
+    /// Records newly fetched data, or the error from fetching it.
+    fn set_data(&mut self, result: FetchResult) {
+        match result {
+            Ok(mut data) => {
+                self.history.record(&data);
+                self.history.apply_indicators(&mut data);
+                sort_data(&mut data, &self.sort_by, self.sort_asc);
+                self.data = data;
+                self.error_message = None;
+                self.last_updated = SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs());
+            }
+            Err(err) => self.error_message = Some(err),
+        }
+    }
+
+    /// Applies a user action. `Refresh` is handled by the event loop.
+    fn update(&mut self, action: Action) {
+        match action {
+            Action::MoveCursor(dx, dy) => {
+                let rows = self.visible_rows();
+                if rows.is_empty() || self.columns.is_empty() {
+                    return;
+                }
+                let (row, column) = match self.cursor_position(&rows) {
+                    Some((row, column)) => (
+                        (row as i32 + dy).clamp(0, rows.len() as i32 - 1) as usize,
+                        (column as i32 + dx).clamp(0, self.columns.len() as i32 - 1) as usize,
+                    ),
+                    None => (0, 0),
+                };
+                let id = rows[row].symbol.clone().unwrap_or_default();
+                self.cursor = Some((id, self.columns[column].key.to_string()));
+            }
+            Action::ToggleCursorSelection => {
+                if let Some((id, column)) = self.cursor.clone() {
+                    toggle_cell_selection(&mut self.selected_cells, id, column);
+                }
+            }
+            Action::SortColumnAt(index) => {
+                if let Some(column) = self.columns.get(index) {
+                    select_sort_column(&mut self.sort_by, &mut self.sort_asc, column.key);
+                    sort_data(&mut self.data, &self.sort_by, self.sort_asc);
+                }
+            }
+            Action::Refresh => {}
+            Action::Quit => self.quit = true,
+        }
+    }
+}
+
+/// Returns the action for a key, or `None` if the key does nothing.
+fn key_action(code: KeyCode) -> Option<Action> {
+    let action = match code {
+        KeyCode::Left => Action::MoveCursor(-1, 0),
+        KeyCode::Right => Action::MoveCursor(1, 0),
+        KeyCode::Up => Action::MoveCursor(0, -1),
+        KeyCode::Down => Action::MoveCursor(0, 1),
+        KeyCode::PageUp => Action::MoveCursor(0, -PAGE_ROWS),
+        KeyCode::PageDown => Action::MoveCursor(0, PAGE_ROWS),
+        KeyCode::Char(' ') => Action::ToggleCursorSelection,
+        KeyCode::Char('r') | KeyCode::Char('R') => Action::Refresh,
+        KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+        KeyCode::Char('0') => Action::SortColumnAt(9),
+        KeyCode::Char(digit @ '1'..='9') => Action::SortColumnAt(digit as usize - '1' as usize),
+        _ => return None,
+    };
+    Some(action)
+}
+
+/// Runs the terminal UI until the user quits, restoring the terminal afterwards.
+pub fn run(args: &Args, columns: Vec<&'static Column>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut app = App::new(args, columns);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app, args);
+    ratatui::restore();
+    result
+}
+
+/// Draws the table, handles keys and starts a background fetch whenever a refresh is due.
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App, args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let refresh = Duration::from_secs(args.refresh);
+    let (sender, receiver): (Sender<FetchResult>, Receiver<FetchResult>) = mpsc::channel();
+    let mut fetching = false;
+    let mut last_fetch: Option<Instant> = None;
+    let mut refresh_now = true;
+
+    while !app.quit {
+        if !fetching && (refresh_now || last_fetch.is_some_and(|t| t.elapsed() >= refresh)) {
+            let (sender, source) = (sender.clone(), args.source());
+            thread::spawn(move || {
+                let _ = sender.send(source.load().map_err(|err| err.to_string()));
+            });
+            fetching = true;
+            refresh_now = false;
+            last_fetch = Some(Instant::now());
+        }
+        if let Ok(result) = receiver.try_recv() {
+            app.set_data(result);
+            fetching = false;
+        }
+
+        terminal.draw(|frame| draw(frame, app, fetching))?;
+
+        if event::poll(Duration::from_millis(POLL_INTERVAL_MS))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key_action(key.code) {
+                        Some(Action::Refresh) => refresh_now = true,
+                        Some(action) => app.update(action),
+                        None => {}
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Renders the table and the status line.
+fn draw(frame: &mut Frame, app: &App, fetching: bool) {
+    let [table_area, status_area] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)])
+        .areas(frame.area());
+    let quote = Quote::default();
+    let rows = app.visible_rows();
+    let cursor = app.cursor_position(&rows);
+
+    let titles: Vec<String> = app.columns.iter().map(|c| {
+        let arrow = match app.sort_by.as_deref() == Some(c.key) {
+            true if app.sort_asc => " \u{25B2}",
+            true => " \u{25BC}",
+            false => "",
+        };
+        format!("{}{}", c.title(&quote, Language::En), arrow)
+    }).collect();
+    let cells: Vec<Vec<String>> = rows.iter()
+        .map(|item| app.columns.iter().map(|c| (c.value)(item, &quote, &NumberLocale::default())).collect())
+        .collect();
+    let widths: Vec<Constraint> = (0..app.columns.len())
+        .map(|i| {
+            let width = cells.iter()
+                .map(|row| row[i].chars().count())
+                .chain([titles[i].chars().count()])
+                .max()
+                .unwrap_or_default();
+            Constraint::Length(width as u16)
+        })
+        .collect();
+
+    let header = Row::new(titles.into_iter().map(Cell::from))
+        .style(Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED));
+    let body = rows.iter().zip(cells).enumerate().map(|(r, (item, values))| {
+        let id = item.symbol.clone().unwrap_or_default();
+        Row::new(values.into_iter().enumerate().map(|(c, value)| {
+            let key = app.columns[c].key;
+            let line = if NUMERIC_COLUMNS.contains(&key) { Line::from(value).right_aligned() } else { Line::from(value) };
+            let mut style = Style::new();
+            if app.selected_cells.contains(&(id.clone(), key.to_string())) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            if cursor == Some((r, c)) {
+                style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            }
+            Cell::from(line).style(style)
+        }))
+    });
+    let mut state = TableState::new().with_selected(cursor.map(|(row, _)| row));
+    frame.render_stateful_widget(Table::new(body, widths).header(header), table_area, &mut state);
+
+    let status = match (&app.error_message, app.last_updated) {
+        (Some(err), _) => format!("Failed to fetch data: {}", err),
+        (None, Some(time)) => format!(
+            "Updated {:02}:{:02}:{:02} UTC \u{00B7} {} coins",
+            time / 3600 % 24,
+            time / 60 % 60,
+            time % 60,
+            rows.len(),
+        ),
+        (None, None) => "Loading\u{2026}".to_string(),
+    };
+    let refreshing = if fetching && app.last_updated.is_some() { " \u{00B7} refreshing" } else { "" };
+    let help = "  \u{2190}\u{2191}\u{2192}\u{2193} move  Space highlight  1\u{2013}9,0 sort  r refresh  q quit";
+    frame.render_widget(Paragraph::new(format!("{}{}{}", status, refreshing, help)), status_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use crypto_screener_core::columns::column;
+
+    fn coin(symbol: &str, volume24: f64) -> CryptoData {
+        CryptoData { symbol: Some(symbol.to_string()), volume24: Some(volume24), ..CryptoData::default() }
+    }
+
+    fn app() -> App {
+        let args = Args::parse_from(["crypto-screener", "--tui"]);
+        let mut app = App::new(&args, vec![column("symbol").unwrap(), column("volume24").unwrap()]);
+        app.set_data(Ok(vec![coin("AAA", 1.0), coin("BBB", 3.0), coin("CCC", 2.0)]));
+        app
+    }
+
+    fn symbols(app: &App) -> Vec<&str> {
+        app.visible_rows().iter().map(|item| item.symbol.as_deref().unwrap()).collect()
+    }
+
+    #[test]
+    fn sorts_by_number_keys_like_the_web_app() {
+        let mut app = app();
+        assert_eq!(symbols(&app), ["BBB", "CCC", "AAA"]);
+        app.update(key_action(KeyCode::Char('1')).unwrap());
+        assert_eq!(symbols(&app), ["AAA", "BBB", "CCC"]);
+        app.update(key_action(KeyCode::Char('1')).unwrap());
+        assert_eq!(symbols(&app), ["CCC", "BBB", "AAA"]);
+    }
+
+    #[test]
+    fn cursor_is_clamped_and_highlights_cells() {
+        let mut app = app();
+        app.update(Action::MoveCursor(0, 0));
+        app.update(Action::MoveCursor(5, PAGE_ROWS));
+        assert_eq!(app.cursor, Some(("AAA".to_string(), "volume24".to_string())));
+        app.update(Action::ToggleCursorSelection);
+        assert!(app.selected_cells.contains(&("AAA".to_string(), "volume24".to_string())));
+    }
+}
@@ -0,0 +1,61 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+
+//! Runs the `crypto-screener` binary against the mock ticker API.
+
+use std::process::{Command, Output};
+use crypto_screener_mock::MockServer;
+
+fn screen(url: &str, extra: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_crypto-screener"))
+        .args(["--url", url, "--format", "csv", "--columns", "symbol"])
+        .args(extra)
+        .output()
+        .expect("runs crypto-screener")
+}
+
+#[test]
+fn screens_mock_tickers() {
+    let server = MockServer::start().unwrap();
+    let output = screen(&server.url("/api/tickers/"), &["--filter", "volume24>1000000000", "--limit", "3"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "symbol\nUSDT\nBTC\nETH\n");
+
+    let output = screen(&server.url("/empty/api/tickers/"), &[]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "symbol\n");
+}
+
+#[test]
+fn fails_on_bad_responses() {
+    let server = MockServer::start().unwrap();
+    for path in ["/malformed/api/tickers/", "/rate-limited/api/tickers/"] {
+        let output = screen(&server.url(path), &[]);
+        assert!(!output.status.success(), "{} should fail", path);
+        assert!(output.stdout.is_empty());
+    }
+    let output = screen(&server.url("/rate-limited/api/tickers/"), &[]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("429"));
+}
@@ -0,0 +1,298 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Columns Module
+//!
+//! This module holds the single definition of the screener's columns: their keys, labels,
+//! default widths and display functions, shared by every front-end, exports and the
+//! selection tools. It also holds the `ColumnLayout` a user arranges them in.
+//!
+//! ## Key Items
+//!
+//! - `Column`: The key, label, default width and display function of one column.
+//! - `COLUMNS`: Every available column, in default order.
+//! - `HIDDEN_BY_DEFAULT`: Columns not shown until the user asks for them.
+//! - `ColumnLayout`: The user's visible columns, order and widths.
+//! - `MIN_WIDTH` / `MAX_WIDTH`: The range a column can be resized within.
+
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use crate::cryptodata::CryptoData;
+use crate::currency::Quote;
+use crate::i18n::{column_label, Language};
+use crate::numformat::NumberLocale;
+
+/// Definition of a single table column.
+pub struct Column {
+    /// Identifier used for sorting, selection, filtering and exports (e.g. "price_usd").
+    pub key: &'static str,
+
+    /// English header label, without a currency; see `localized` for other languages.
+    pub label: &'static str,
+
+    /// Whether values are amounts of money, converted into the quote currency.
+    pub monetary: bool,
+
+    /// Default width in pixels.
+    pub width: u32,
+
+    /// Returns the display text of this column for a row, in the given quote currency and
+    /// with the given number separators.
+    pub value: fn(&CryptoData, &Quote, &NumberLocale) -> String,
+}
+
+impl Column {
+    /// Returns the label in `lang`, falling back to the English `label`.
+    pub fn localized(&self, lang: Language) -> &'static str {
+        column_label(lang, self.key, self.label)
+    }
+
+    /// Returns the header text in `lang`, naming the quote currency for monetary columns,
+    /// e.g. "Price (EUR)".
+    pub fn title(&self, quote: &Quote, lang: Language) -> String {
+        if self.monetary {
+            format!("{} ({})", self.localized(lang), quote.code)
+        } else {
+            self.localized(lang).to_string()
+        }
+    }
+}
+
+/// Every available column, in default display order.
+pub static COLUMNS: [Column; 12] = [
+    Column { key: "symbol", label: "Symbol", monetary: false, width: 80, value: |c, _, _| c.symbol.clone().unwrap_or_default() },
+    Column { key: "name", label: "Name", monetary: false, width: 200, value: |c, _, _| c.truncated_name() },
+    Column { key: "price_usd", label: "Price", monetary: true, width: 120, value: |c, q, l| c.formatted_price(q, l) },
+    Column { key: "percent_change_1h", label: "1h %", monetary: false, width: 70, value: |c, _, l| c.formatted_percent_change_1h(l) },
+    Column { key: "percent_change_24h", label: "24h %", monetary: false, width: 70, value: |c, _, l| c.formatted_percent_change_24h(l) },
+    Column { key: "percent_change_7d", label: "7d %", monetary: false, width: 70, value: |c, _, l| c.formatted_percent_change_7d(l) },
+    Column { key: "ema_sma_spread", label: "EMA/SMA %", monetary: false, width: 90, value: |c, _, l| CryptoData::formatted_indicator(c.ema_sma_spread, l) },
+    Column { key: "rsi_14", label: "RSI(14)", monetary: false, width: 80, value: |c, _, l| CryptoData::formatted_indicator(c.rsi_14, l) },
+    Column { key: "volatility", label: "Volatility %", monetary: false, width: 100, value: |c, _, l| CryptoData::formatted_indicator(c.volatility, l) },
+    Column { key: "volume_zscore", label: "Vol z-score", monetary: false, width: 100, value: |c, _, l| CryptoData::formatted_indicator(c.volume_zscore, l) },
+    Column { key: "volume24", label: "Volume", monetary: true, width: 150, value: |c, q, l| c.formatted_volume(q, l) },
+    Column { key: "market_cap_usd", label: "Market Cap", monetary: true, width: 150, value: |c, q, l| c.formatted_market_cap(q, l) },
+];
+
+/// Columns hidden until the user chooses to show them.
+pub const HIDDEN_BY_DEFAULT: [&str; 1] = ["market_cap_usd"];
+
+/// Looks up a column definition by key.
+pub fn column(key: &str) -> Option<&'static Column> {
+    COLUMNS.iter().find(|c| c.key == key)
+}
+
+/// Narrowest width a column can be resized to, in pixels.
+pub const MIN_WIDTH: u32 = 40;
+
+/// Widest width a column can be resized to, in pixels.
+pub const MAX_WIDTH: u32 = 600;
+
+/// The user's choice of visible columns, their order and their widths.
+///
+/// Serializable, so front-ends can persist it. Keys that no longer name a column are
+/// dropped by `normalized`, and columns added since the layout was saved are appended.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnLayout {
+    /// Every column key, in display order.
+    pub order: Vec<String>,
+
+    /// Keys of columns the user has hidden.
+    pub hidden: HashSet<String>,
+
+    /// Widths the user has set, in pixels; columns not listed use their default width.
+    pub widths: HashMap<String, u32>,
+}
+
+impl Default for ColumnLayout {
+    fn default() -> Self {
+        ColumnLayout {
+            order: COLUMNS.iter().map(|c| c.key.to_string()).collect(),
+            hidden: HIDDEN_BY_DEFAULT.iter().map(|k| k.to_string()).collect(),
+            widths: HashMap::new(),
+        }
+    }
+}
+
+impl ColumnLayout {
+    /// Returns a layout showing just the columns in `keys`, in that order, with the
+    /// other columns hidden after them. Unknown keys are ignored; if none is known, the
+    /// default layout is returned.
+    pub fn showing(keys: &[String]) -> Self {
+        let mut shown: Vec<String> = Vec::new();
+        for key in keys {
+            if column(key).is_some() && !shown.contains(key) {
+                shown.push(key.clone());
+            }
+        }
+        if shown.is_empty() {
+            return ColumnLayout::default();
+        }
+        let hidden = COLUMNS
+            .iter()
+            .map(|c| c.key.to_string())
+            .filter(|key| !shown.contains(key))
+            .collect();
+        ColumnLayout { order: shown, hidden, widths: HashMap::new() }.normalized()
+    }
+
+    /// Drops unknown keys and appends columns missing from `order`, so a layout saved by
+    /// an older version still names every column exactly once.
+    pub fn normalized(mut self) -> Self {
+        self.order.retain(|key| column(key).is_some());
+        for c in COLUMNS.iter() {
+            if !self.order.iter().any(|key| key == c.key) {
+                self.order.push(c.key.to_string());
+            }
+        }
+        self.hidden.retain(|key| column(key).is_some());
+        self.widths.retain(|key, _| column(key).is_some());
+        if self.visible().is_empty() {
+            self.hidden.clear();
+        }
+        self
+    }
+
+    /// Returns all columns in display order, visible or not.
+    pub fn ordered(&self) -> Vec<&'static Column> {
+        self.order.iter().filter_map(|key| column(key)).collect()
+    }
+
+    /// Returns the visible columns in display order.
+    pub fn visible(&self) -> Vec<&'static Column> {
+        self.ordered()
+            .into_iter()
+            .filter(|c| !self.hidden.contains(c.key))
+            .collect()
+    }
+
+    /// Returns the keys of the visible columns in display order.
+    pub fn visible_keys(&self) -> Vec<&'static str> {
+        self.visible().iter().map(|c| c.key).collect()
+    }
+
+    /// Returns `true` if the column is shown.
+    pub fn is_visible(&self, key: &str) -> bool {
+        !self.hidden.contains(key)
+    }
+
+    /// Returns the width of a column in pixels.
+    pub fn width(&self, column: &Column) -> u32 {
+        self.widths.get(column.key).copied().unwrap_or(column.width)
+    }
+
+    /// Shows a hidden column or hides a visible one. The last visible column cannot be hidden.
+    pub fn toggle(&mut self, key: &str) {
+        if !self.hidden.remove(key) && self.visible().len() > 1 {
+            self.hidden.insert(key.to_string());
+        }
+    }
+
+    /// Moves column `from` to the position currently occupied by column `to`.
+    pub fn move_column(&mut self, from: &str, to: &str) {
+        let Some(from_index) = self.order.iter().position(|k| k == from) else {
+            return;
+        };
+        let Some(to_index) = self.order.iter().position(|k| k == to) else {
+            return;
+        };
+        let key = self.order.remove(from_index);
+        self.order.insert(to_index, key);
+    }
+
+    /// Sets the width of a column, clamped to `MIN_WIDTH..=MAX_WIDTH`.
+    pub fn resize(&mut self, key: &str, width: u32) {
+        self.widths.insert(key.to_string(), width.clamp(MIN_WIDTH, MAX_WIDTH));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(order: &[&str], hidden: &[&str]) -> ColumnLayout {
+        ColumnLayout {
+            order: order.iter().map(|k| k.to_string()).collect(),
+            hidden: hidden.iter().map(|k| k.to_string()).collect(),
+            widths: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn normalizing_drops_unknown_keys_and_appends_new_columns() {
+        let mut saved = layout(&["name", "retired", "symbol"], &["retired", "name"]);
+        saved.widths.insert("retired".to_string(), 90);
+        saved.widths.insert("name".to_string(), 90);
+
+        let layout = saved.normalized();
+        assert_eq!(layout.order.len(), COLUMNS.len());
+        assert_eq!(&layout.order[..2], ["name", "symbol"]);
+        assert_eq!(layout.order[2], COLUMNS[2].key);
+        assert_eq!(layout.hidden, HashSet::from(["name".to_string()]));
+        assert_eq!(layout.widths, HashMap::from([("name".to_string(), 90)]));
+    }
+
+    #[test]
+    fn normalizing_shows_everything_when_all_is_hidden() {
+        let all: Vec<&str> = COLUMNS.iter().map(|c| c.key).collect();
+        let layout = layout(&all, &all).normalized();
+        assert!(layout.hidden.is_empty());
+        assert_eq!(layout.visible_keys(), all);
+    }
+
+    #[test]
+    fn moves_a_column_into_anothers_place() {
+        let mut layout = layout(&["symbol", "name", "price_usd", "volume24"], &[]);
+        layout.move_column("volume24", "name");
+        assert_eq!(layout.order, ["symbol", "volume24", "name", "price_usd"]);
+        layout.move_column("symbol", "price_usd");
+        assert_eq!(layout.order, ["volume24", "name", "price_usd", "symbol"]);
+        layout.move_column("symbol", "missing");
+        assert_eq!(layout.order, ["volume24", "name", "price_usd", "symbol"]);
+    }
+
+    #[test]
+    fn resizing_clamps_the_width() {
+        let mut layout = ColumnLayout::default();
+        let name = column("name").unwrap();
+        assert_eq!(layout.width(name), name.width);
+        layout.resize("name", 10);
+        assert_eq!(layout.width(name), MIN_WIDTH);
+        layout.resize("name", 10_000);
+        assert_eq!(layout.width(name), MAX_WIDTH);
+        layout.resize("name", 250);
+        assert_eq!(layout.width(name), 250);
+    }
+
+    #[test]
+    fn keeps_the_last_visible_column() {
+        let mut layout = layout(&["symbol", "name"], &[]).normalized();
+        for c in COLUMNS.iter().skip(2) {
+            layout.toggle(c.key);
+        }
+        layout.toggle("name");
+        layout.toggle("symbol");
+        assert_eq!(layout.visible_keys(), ["symbol"]);
+    }
+}
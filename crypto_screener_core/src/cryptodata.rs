@@ -0,0 +1,284 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # CryptoData Module
+//!
+//! This module defines the `CryptoData` struct, which represents a data record
+//! for a cryptocurrency. It includes fields for various metrics such as price,
+//! percent changes, and trading volume, and provides methods to format and
+//! display these values in a user-friendly way. `parse_tickers` turns a ticker API
+//! response into records, however the response was obtained.
+
+use serde::Deserialize;
+use serde_json::Value;
+use crate::currency::Quote;
+use crate::numformat::{format_compact, format_fixed, format_percent, format_significant, NumberLocale};
+
+/// The Coinlore ticker endpoint the screener reads by default.
+pub const DEFAULT_TICKERS_URL: &str = "https://api.coinlore.net/api/tickers/";
+
+/// Represents cryptocurrency data, including name, symbol, price, percent change,
+/// and 24-hour trading volume. This struct is designed to hold data parsed
+/// from a JSON source, with all fields being optional to account for missing values.
+///
+/// The technical indicator fields are not part of the JSON source; they are computed
+/// from recorded history after each fetch (see `History::apply_indicators`).
+#[derive(Deserialize, Debug, Default, PartialEq)]
+pub struct CryptoData {
+    /// The ticker API's identifier for the cryptocurrency. Unlike the symbol, it is unique.
+    pub id: Option<String>,
+
+    /// The symbol or ticker of the cryptocurrency (e.g., BTC for Bitcoin).
+    pub symbol: Option<String>,
+    
+    /// The full name of the cryptocurrency (e.g., Bitcoin).
+    pub name: Option<String>,
+    
+    /// The current price of the cryptocurrency in USD.
+    pub price_usd: Option<String>,
+    
+    /// The percent change in price over the last hour.
+    pub percent_change_1h: Option<String>,
+    
+    /// The percent change in price over the last 24 hours.
+    pub percent_change_24h: Option<String>,
+    
+    /// The percent change in price over the last 7 days.
+    pub percent_change_7d: Option<String>,
+    
+    /// The 24-hour trading volume of the cryptocurrency.
+    pub volume24: Option<f64>,
+
+    /// The market capitalization of the cryptocurrency in USD.
+    pub market_cap_usd: Option<String>,
+
+    /// Percent spread of the fast EMA over the slow SMA of recorded prices.
+    #[serde(skip)]
+    pub ema_sma_spread: Option<f64>,
+
+    /// 14-period Relative Strength Index of recorded prices.
+    #[serde(skip)]
+    pub rsi_14: Option<f64>,
+
+    /// Rolling volatility of recorded price returns, in percent.
+    #[serde(skip)]
+    pub volatility: Option<f64>,
+
+    /// Z-score of the latest recorded volume against its rolling window.
+    #[serde(skip)]
+    pub volume_zscore: Option<f64>,
+}
+
+/// Column names whose values can be read as numbers with `CryptoData::numeric_value`.
+pub const NUMERIC_COLUMNS: [&str; 10] = [
+    "price_usd",
+    "percent_change_1h",
+    "percent_change_24h",
+    "percent_change_7d",
+    "ema_sma_spread",
+    "rsi_14",
+    "volatility",
+    "volume_zscore",
+    "volume24",
+    "market_cap_usd",
+];
+
+impl CryptoData {
+    /// Returns a truncated version of the cryptocurrency name, limited to 30 characters.
+    ///
+    /// If `name` is `None`, returns an empty string. This is useful for displaying
+    /// abbreviated names in UI components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// let crypto = CryptoData { name: Some("Bitcoin".to_string()), ..Default::default() };
+    /// assert_eq!(crypto.truncated_name(), "Bitcoin");
+    /// ```
+    pub fn truncated_name(&self) -> String {
+        self.name.clone().unwrap_or_default().chars().take(30).collect()
+    }
+
+    /// Returns the price in the `quote` currency with `locale` separators, if parsable as a
+    /// `f64`. Prices are rounded to two decimals, or to four significant digits where that
+    /// needs more decimals (e.g. `0.00001235`); prices too small even for that are shown in
+    /// scientific notation (see `format_significant`).
+    ///
+    /// If the price cannot be parsed or is `None`, returns the original `price_usd`
+    /// string value or an empty string if `price_usd` is also `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// # use crypto_screener_core::currency::Quote;
+    /// # use crypto_screener_core::numformat::NumberLocale;
+    /// let crypto = CryptoData { price_usd: Some("1234.56789".to_string()), ..Default::default() };
+    /// assert_eq!(crypto.formatted_price(&Quote::default(), &NumberLocale::default()), "1,234.57");
+    /// ```
+    pub fn formatted_price(&self, quote: &Quote, locale: &NumberLocale) -> String {
+        format_parsed(&self.price_usd, |v| format_significant(quote.convert(v), locale))
+    }
+
+    /// Returns the 1-hour percent change with an explicit sign, e.g. `+1.25`.
+    ///
+    /// Unparsable values are returned as-is, and `None` as an empty string.
+    pub fn formatted_percent_change_1h(&self, locale: &NumberLocale) -> String {
+        format_parsed(&self.percent_change_1h, |v| format_percent(v, locale))
+    }
+
+    /// Returns the 24-hour percent change with an explicit sign, e.g. `+1.25`.
+    ///
+    /// Unparsable values are returned as-is, and `None` as an empty string.
+    pub fn formatted_percent_change_24h(&self, locale: &NumberLocale) -> String {
+        format_parsed(&self.percent_change_24h, |v| format_percent(v, locale))
+    }
+
+    /// Returns the 7-day percent change with an explicit sign, e.g. `+1.25`.
+    ///
+    /// Unparsable values are returned as-is, and `None` as an empty string.
+    pub fn formatted_percent_change_7d(&self, locale: &NumberLocale) -> String {
+        format_parsed(&self.percent_change_7d, |v| format_percent(v, locale))
+    }
+
+    /// Returns the 24-hour trading volume in the `quote` currency in compact notation,
+    /// e.g. `1.2B`.
+    ///
+    /// If `volume24` is `None`, returns an empty string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// # use crypto_screener_core::currency::Quote;
+    /// # use crypto_screener_core::numformat::NumberLocale;
+    /// let crypto = CryptoData { volume24: Some(12345.6789), ..Default::default() };
+    /// assert_eq!(crypto.formatted_volume(&Quote::default(), &NumberLocale::default()), "12.3K");
+    /// ```
+    pub fn formatted_volume(&self, quote: &Quote, locale: &NumberLocale) -> String {
+        self.volume24.map(|v| format_compact(quote.convert(v), locale)).unwrap_or_default()
+    }
+
+    /// Returns the market capitalization in the `quote` currency in compact notation,
+    /// e.g. `340M`, if parsable as a `f64`.
+    ///
+    /// If the market cap cannot be parsed or is `None`, returns the original
+    /// `market_cap_usd` string value or an empty string.
+    pub fn formatted_market_cap(&self, quote: &Quote, locale: &NumberLocale) -> String {
+        format_parsed(&self.market_cap_usd, |v| format_compact(quote.convert(v), locale))
+    }
+
+    /// Returns a computed indicator formatted to two decimal places.
+    ///
+    /// If the indicator has not been computed yet (not enough history), returns an
+    /// empty string.
+    pub fn formatted_indicator(value: Option<f64>, locale: &NumberLocale) -> String {
+        value.map(|v| format_fixed(v, 2, locale)).unwrap_or_default()
+    }
+
+    /// Returns the value of a numeric column as an `f64`.
+    ///
+    /// String fields are parsed; returns `None` if the value is missing, cannot be
+    /// parsed, or `column` is not one of `NUMERIC_COLUMNS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// let crypto = CryptoData { price_usd: Some("1.5".to_string()), ..Default::default() };
+    /// assert_eq!(crypto.numeric_value("price_usd"), Some(1.5));
+    /// ```
+    pub fn numeric_value(&self, column: &str) -> Option<f64> {
+        let parse = |s: &Option<String>| s.as_ref().and_then(|s| s.parse::<f64>().ok());
+        match column {
+            "price_usd" => parse(&self.price_usd),
+            "percent_change_1h" => parse(&self.percent_change_1h),
+            "percent_change_24h" => parse(&self.percent_change_24h),
+            "percent_change_7d" => parse(&self.percent_change_7d),
+            "ema_sma_spread" => self.ema_sma_spread,
+            "rsi_14" => self.rsi_14,
+            "volatility" => self.volatility,
+            "volume_zscore" => self.volume_zscore,
+            "volume24" => self.volume24,
+            "market_cap_usd" => parse(&self.market_cap_usd),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw value of a column as JSON, without display formatting.
+    ///
+    /// Text columns become strings and numeric columns become numbers. Missing or
+    /// unparsable values, and unknown columns, become `Value::Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// let crypto = CryptoData { volume24: Some(12345.6789), ..Default::default() };
+    /// assert_eq!(crypto.raw_value("volume24"), serde_json::json!(12345.6789));
+    /// ```
+    pub fn raw_value(&self, column: &str) -> Value {
+        match column {
+            "symbol" => self.symbol.clone().map(Value::String).unwrap_or(Value::Null),
+            "name" => self.name.clone().map(Value::String).unwrap_or(Value::Null),
+            _ => self.numeric_value(column).map(Value::from).unwrap_or(Value::Null),
+        }
+    }
+}
+
+/// Parses a ticker API response into `CryptoData` records.
+///
+/// Records are read from the response's `data` array; entries that don't have the
+/// expected shape are skipped. A response without a `data` array yields no records.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::cryptodata::parse_tickers;
+/// let data = parse_tickers(&serde_json::json!({ "data": [{ "symbol": "BTC" }] }));
+/// assert_eq!(data[0].symbol.as_deref(), Some("BTC"));
+/// ```
+pub fn parse_tickers(payload: &Value) -> Vec<CryptoData> {
+    payload
+        .get("data")
+        .and_then(|d| d.as_array())
+        .map(|array| {
+            array
+                .iter()
+                .filter_map(|item| serde_json::from_value(item.clone()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parses a string field and formats it with `format`.
+///
+/// Unparsable values are returned unchanged, and `None` as an empty string.
+fn format_parsed(field: &Option<String>, format: impl Fn(f64) -> String) -> String {
+    match field {
+        Some(raw) => raw.parse::<f64>().map(format).unwrap_or_else(|_| raw.clone()),
+        None => String::new(),
+    }
+}
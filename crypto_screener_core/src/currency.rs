@@ -0,0 +1,177 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Quote Currency Module
+//!
+//! This module converts the screener's USD-denominated prices, volumes and market caps
+//! into another quote currency. Fiat rates come from a rates endpoint (fetched by the
+//! front-end, which decides where the endpoint is); BTC and ETH rates are derived from
+//! the screener's own ticker data.
+//!
+//! ## Key Items
+//!
+//! - `Quote`: A quote currency code and its rate per US dollar.
+//! - `FIAT_QUOTES` / `CRYPTO_QUOTES`: The currencies offered by the selector.
+//! - `DEFAULT_RATES_URL`: The public rates endpoint used unless overridden.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use crate::cryptodata::CryptoData;
+
+/// Fiat currencies offered by the quote selector, converted with fetched rates.
+pub const FIAT_QUOTES: [&str; 8] = ["USD", "EUR", "GBP", "JPY", "CAD", "AUD", "CHF", "CNY"];
+
+/// Cryptocurrencies offered by the quote selector, converted with their ticker prices.
+pub const CRYPTO_QUOTES: [&str; 2] = ["BTC", "ETH"];
+
+/// Endpoint returning `{"rates": {"EUR": 0.92, ...}}` relative to one US dollar.
+pub const DEFAULT_RATES_URL: &str = "https://open.er-api.com/v6/latest/USD";
+
+/// A quote currency and how many of its units one US dollar buys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quote {
+    /// Currency code shown in column headers (e.g. "EUR").
+    pub code: String,
+
+    /// Units of this currency per US dollar.
+    pub rate: f64,
+}
+
+impl Default for Quote {
+    /// The US dollar, which needs no conversion.
+    fn default() -> Self {
+        Quote { code: "USD".to_string(), rate: 1.0 }
+    }
+}
+
+impl Quote {
+    /// Returns the quote for `code`, or `None` if its rate is not known yet.
+    ///
+    /// Fiat rates are looked up in `rates`; BTC and ETH use the inverse of their USD price
+    /// in `data`. Tokens can share a ticker symbol, so the row with the largest market cap
+    /// is used, whatever order `data` is in. `data` may hold rows directly or behind any
+    /// pointer that borrows as `CryptoData`, such as `Rc`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// # use crypto_screener_core::currency::Quote;
+    /// let data: &[CryptoData] = &[];
+    /// let quote = Quote::resolve("EUR", &HashMap::from([("EUR".to_string(), 0.9)]), data);
+    /// assert_eq!(quote.unwrap().convert(10.0), 9.0);
+    /// ```
+    pub fn resolve<T>(code: &str, rates: &HashMap<String, f64>, data: &[T]) -> Option<Quote>
+    where
+        T: Borrow<CryptoData>,
+    {
+        let rate = if code == "USD" {
+            Some(1.0)
+        } else if CRYPTO_QUOTES.contains(&code) {
+            data.iter()
+                .map(T::borrow)
+                .filter(|item| item.symbol.as_deref() == Some(code))
+                .max_by(|a, b| {
+                    let cap = |item: &CryptoData| item.numeric_value("market_cap_usd").unwrap_or(0.0);
+                    cap(a).total_cmp(&cap(b))
+                })
+                .and_then(|item| item.numeric_value("price_usd"))
+                .filter(|price| *price > 0.0)
+                .map(|price| 1.0 / price)
+        } else {
+            rates.get(code).copied()
+        };
+        rate.filter(|rate| rate.is_finite() && *rate > 0.0)
+            .map(|rate| Quote { code: code.to_string(), rate })
+    }
+
+    /// Converts an amount in US dollars into this currency.
+    pub fn convert(&self, usd: f64) -> f64 {
+        usd * self.rate
+    }
+}
+
+/// Whether `code` needs rates from the rates endpoint.
+pub fn needs_rates(code: &str) -> bool {
+    code != "USD" && FIAT_QUOTES.contains(&code)
+}
+
+/// Whether `code` is one of the offered quote currencies.
+pub fn is_supported(code: &str) -> bool {
+    FIAT_QUOTES.contains(&code) || CRYPTO_QUOTES.contains(&code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    const NO_DATA: &[CryptoData] = &[];
+
+    fn coin(symbol: &str, price: &str, market_cap: &str) -> CryptoData {
+        CryptoData {
+            symbol: Some(symbol.to_string()),
+            price_usd: Some(price.to_string()),
+            market_cap_usd: Some(market_cap.to_string()),
+            ..CryptoData::default()
+        }
+    }
+
+    #[test]
+    fn resolves_usd_and_fiat_rates() {
+        let rates = HashMap::from([("EUR".to_string(), 0.9)]);
+        assert_eq!(Quote::resolve("USD", &rates, NO_DATA), Some(Quote::default()));
+        assert_eq!(Quote::resolve("EUR", &rates, NO_DATA).map(|q| q.rate), Some(0.9));
+        assert_eq!(Quote::resolve("GBP", &rates, NO_DATA), None);
+    }
+
+    #[test]
+    fn rejects_unusable_rates() {
+        let rates = HashMap::from([("EUR".to_string(), 0.0), ("JPY".to_string(), f64::NAN)]);
+        assert_eq!(Quote::resolve("EUR", &rates, NO_DATA), None);
+        assert_eq!(Quote::resolve("JPY", &rates, NO_DATA), None);
+        assert_eq!(Quote::resolve("BTC", &rates, &[coin("BTC", "0", "1")]), None);
+    }
+
+    #[test]
+    fn resolves_crypto_rates_from_the_largest_coin() {
+        let rates = HashMap::new();
+        let data = || [coin("ETH", "0.5", "1000"), coin("ETH", "2000", "200000000000"), coin("ETH", "4", "")];
+        let mut reversed = data();
+        reversed.reverse();
+        let data = data().map(Rc::new);
+
+        assert_eq!(Quote::resolve("ETH", &rates, &data).map(|q| q.rate), Some(0.0005));
+        assert_eq!(Quote::resolve("ETH", &rates, &reversed).map(|q| q.rate), Some(0.0005));
+        assert_eq!(Quote::resolve("BTC", &rates, &data), None);
+    }
+
+    #[test]
+    fn converts_from_usd() {
+        let quote = Quote { code: "EUR".to_string(), rate: 0.5 };
+        assert_eq!(quote.convert(10.0), 5.0);
+        assert_eq!(Quote::default().convert(10.0), 10.0);
+    }
+}
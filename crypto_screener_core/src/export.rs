@@ -0,0 +1,246 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Export Module
+//!
+//! This module turns rows into CSV or JSON, and highlighted cells into a tab-separated
+//! grid. Values are exported raw (numbers as numbers, not the `formatted_*` display
+//! strings), in the given row and column order.
+//!
+//! ## Key Functions
+//!
+//! - `to_csv`: Renders rows as CSV with a header line of column names.
+//! - `to_json`: Renders rows as a JSON array of objects keyed by column name.
+//! - `selection_tsv`: Renders highlighted cells as a tab-separated grid for the clipboard.
+
+use std::collections::HashSet;
+use serde_json::{Map, Value};
+use crate::cryptodata::CryptoData;
+
+/// File formats rows can be exported in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// Returns the filename extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Returns the MIME type for the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+        }
+    }
+
+    /// Renders `rows` in this format, limited to `columns` in the given order.
+    pub fn render<'a>(&self, rows: impl Iterator<Item = &'a CryptoData>, columns: &[&str]) -> String {
+        match self {
+            ExportFormat::Csv => to_csv(rows, columns),
+            ExportFormat::Json => to_json(rows, columns),
+        }
+    }
+}
+
+/// Renders `rows` as CSV, with a header line of column names.
+///
+/// Missing values are left empty; text containing commas, quotes or line breaks is quoted.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::cryptodata::CryptoData;
+/// # use crypto_screener_core::export::to_csv;
+/// let btc = CryptoData { symbol: Some("BTC".to_string()), price_usd: Some("67000.5".to_string()), ..Default::default() };
+/// let csv = to_csv([&btc].into_iter(), &["symbol", "price_usd"]);
+/// assert_eq!(csv, "symbol,price_usd\nBTC,67000.5\n");
+/// ```
+pub fn to_csv<'a>(rows: impl Iterator<Item = &'a CryptoData>, columns: &[&str]) -> String {
+    let mut csv = columns.join(",");
+    csv.push('\n');
+    for item in rows {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| match item.raw_value(column) {
+                Value::Null => String::new(),
+                Value::String(s) => csv_escape(&s),
+                other => other.to_string(),
+            })
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Renders `rows` as a pretty-printed JSON array of objects keyed by column name.
+///
+/// Missing values are exported as `null`.
+pub fn to_json<'a>(rows: impl Iterator<Item = &'a CryptoData>, columns: &[&str]) -> String {
+    let array: Vec<Value> = rows
+        .map(|item| {
+            let object: Map<String, Value> = columns
+                .iter()
+                .map(|column| (column.to_string(), item.raw_value(column)))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+    serde_json::to_string_pretty(&array).unwrap_or_default()
+}
+
+/// Renders the selected cells of `rows` as a tab-separated grid.
+///
+/// The grid keeps the layout of the table: it contains every row (in the given order)
+/// and every column (in `columns` order) with at least one selected cell, and leaves
+/// unselected cells within that rectangle empty. Values are raw, so numbers paste into
+/// spreadsheets as numbers. Returns `None` if none of `rows` has a selected cell.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use crypto_screener_core::cryptodata::CryptoData;
+/// # use crypto_screener_core::export::selection_tsv;
+/// let btc = CryptoData { symbol: Some("BTC".to_string()), price_usd: Some("67000.5".to_string()), ..Default::default() };
+/// let selected = HashSet::from([("BTC".to_string(), "price_usd".to_string())]);
+/// let tsv = selection_tsv([&btc].into_iter(), &["symbol", "price_usd"], &selected);
+/// assert_eq!(tsv.as_deref(), Some("67000.5"));
+/// ```
+pub fn selection_tsv<'a>(
+    rows: impl Iterator<Item = &'a CryptoData>,
+    columns: &[&str],
+    selected_cells: &HashSet<(String, String)>,
+) -> Option<String> {
+    let is_selected = |item: &CryptoData, column: &str| {
+        let id = item.symbol.clone().unwrap_or_default();
+        selected_cells.contains(&(id, column.to_string()))
+    };
+    let rows: Vec<&CryptoData> = rows
+        .filter(|item| columns.iter().any(|column| is_selected(item, column)))
+        .collect();
+    let columns: Vec<&str> = columns
+        .iter()
+        .copied()
+        .filter(|column| rows.iter().any(|item| is_selected(item, column)))
+        .collect();
+    if rows.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|item| {
+            let fields: Vec<String> = columns
+                .iter()
+                .map(|column| match item.raw_value(column) {
+                    _ if !is_selected(item, column) => String::new(),
+                    Value::Null => String::new(),
+                    Value::String(s) => s.replace(['\t', '\n', '\r'], " "),
+                    other => other.to_string(),
+                })
+                .collect();
+            fields.join("\t")
+        })
+        .collect();
+    Some(lines.join("\n"))
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or line break.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(symbol: &str, name: &str, price: &str) -> CryptoData {
+        CryptoData {
+            symbol: Some(symbol.to_string()),
+            name: Some(name.to_string()),
+            price_usd: Some(price.to_string()),
+            ..CryptoData::default()
+        }
+    }
+
+    fn cells(cells: &[(&str, &str)]) -> HashSet<(String, String)> {
+        cells.iter().map(|(id, column)| (id.to_string(), column.to_string())).collect()
+    }
+
+    #[test]
+    fn quotes_csv_fields_that_need_it() {
+        assert_eq!(csv_escape("Bitcoin"), "Bitcoin");
+        assert_eq!(csv_escape("Wrapped, Bitcoin"), "\"Wrapped, Bitcoin\"");
+        assert_eq!(csv_escape("The \"Coin\""), "\"The \"\"Coin\"\"\"");
+        assert_eq!(csv_escape("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn csv_leaves_missing_values_empty() {
+        let rows = [coin("A,B", "Ay Bee", "x"), CryptoData::default()];
+        let csv = to_csv(rows.iter(), &["symbol", "name", "price_usd"]);
+        assert_eq!(csv, "symbol,name,price_usd\n\"A,B\",Ay Bee,\n,,\n");
+    }
+
+    #[test]
+    fn json_keeps_column_order_and_nulls() {
+        let rows = [coin("BTC", "Bitcoin", "1.5")];
+        let json: Value = serde_json::from_str(&to_json(rows.iter(), &["price_usd", "volume24"])).unwrap();
+        assert_eq!(json, serde_json::json!([{ "price_usd": 1.5, "volume24": null }]));
+    }
+
+    #[test]
+    fn tsv_keeps_the_selected_rectangle() {
+        let rows = [coin("BTC", "Bitcoin", "2.5"), coin("ETH", "Ether", "1.5"), coin("SOL", "Solana", "0.5")];
+        let selected = cells(&[("BTC", "name"), ("SOL", "price_usd")]);
+        let tsv = selection_tsv(rows.iter(), &["symbol", "name", "price_usd"], &selected);
+        assert_eq!(tsv.as_deref(), Some("Bitcoin\t\n\t0.5"));
+    }
+
+    #[test]
+    fn tsv_flattens_tabs_and_line_breaks() {
+        let rows = [coin("BTC", "Bit\tcoin\nCash", "1")];
+        let tsv = selection_tsv(rows.iter(), &["name"], &cells(&[("BTC", "name")]));
+        assert_eq!(tsv.as_deref(), Some("Bit coin Cash"));
+    }
+
+    #[test]
+    fn tsv_without_a_selection() {
+        let rows = [coin("BTC", "Bitcoin", "1")];
+        assert_eq!(selection_tsv(rows.iter(), &["name"], &HashSet::new()), None);
+    }
+}
@@ -0,0 +1,180 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Filter Module
+//!
+//! This module provides the `Filter` struct, which decides which rows of cryptocurrency data
+//! are shown. A filter is parsed from a single query string of whitespace-separated terms,
+//! all of which must match:
+//!
+//! - A comparison such as `rsi_14>70` or `volume24>=1000000` tests a numeric column
+//!   (any of `NUMERIC_COLUMNS`) using `<`, `<=`, `>`, `>=` or `=`.
+//! - Any other term is matched case-insensitively against the symbol and name.
+
+use crate::cryptodata::{CryptoData, NUMERIC_COLUMNS};
+
+/// Comparison operators supported in numeric filter terms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Op {
+    /// Applies the comparison to `value` and `bound`.
+    fn test(self, value: f64, bound: f64) -> bool {
+        match self {
+            Op::Lt => value < bound,
+            Op::Le => value <= bound,
+            Op::Gt => value > bound,
+            Op::Ge => value >= bound,
+            Op::Eq => value == bound,
+        }
+    }
+}
+
+/// A numeric condition on a single column, e.g. `percent_change_24h>5`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    /// The column to test, one of `NUMERIC_COLUMNS`.
+    pub column: String,
+
+    /// The comparison to apply.
+    pub op: Op,
+
+    /// The value to compare against.
+    pub value: f64,
+}
+
+/// A parsed filter query.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filter {
+    /// Lowercased text terms matched against symbol and name.
+    pub terms: Vec<String>,
+
+    /// Numeric conditions on columns.
+    pub conditions: Vec<Condition>,
+}
+
+impl Filter {
+    /// Parses a filter from a query string.
+    ///
+    /// Terms that look like comparisons but name an unknown column or have an unparsable
+    /// value are treated as text terms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crypto_screener_core::filter::Filter;
+    /// let filter = Filter::parse("coin volume24>1000");
+    /// assert_eq!(filter.terms, vec!["coin".to_string()]);
+    /// assert_eq!(filter.conditions.len(), 1);
+    /// ```
+    pub fn parse(query: &str) -> Self {
+        let mut filter = Filter::default();
+        for token in query.split_whitespace() {
+            match parse_condition(token) {
+                Some(condition) => filter.conditions.push(condition),
+                None => filter.terms.push(token.to_lowercase()),
+            }
+        }
+        filter
+    }
+
+    /// Returns `true` if `item` satisfies every term and condition of the filter.
+    ///
+    /// Rows with a missing value for a filtered column never match that condition.
+    pub fn matches(&self, item: &CryptoData) -> bool {
+        let symbol = item.symbol.as_deref().unwrap_or_default().to_lowercase();
+        let name = item.name.as_deref().unwrap_or_default().to_lowercase();
+        self.terms.iter().all(|t| symbol.contains(t) || name.contains(t))
+            && self.conditions.iter().all(|c| {
+                item.numeric_value(&c.column)
+                    .is_some_and(|v| c.op.test(v, c.value))
+            })
+    }
+}
+
+/// Parses a single `column<op>value` token, returning `None` if it is not a valid condition.
+fn parse_condition(token: &str) -> Option<Condition> {
+    const OPS: [(&str, Op); 5] = [
+        ("<=", Op::Le),
+        (">=", Op::Ge),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+        ("=", Op::Eq),
+    ];
+    let (column, op, value) = OPS.iter().find_map(|(text, op)| {
+        token.split_once(text).map(|(column, value)| (column, *op, value))
+    })?;
+    if !NUMERIC_COLUMNS.contains(&column) {
+        return None;
+    }
+    Some(Condition {
+        column: column.to_string(),
+        op,
+        value: value.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(symbol: &str, name: &str, volume24: Option<f64>) -> CryptoData {
+        CryptoData {
+            symbol: Some(symbol.to_string()),
+            name: Some(name.to_string()),
+            volume24,
+            ..CryptoData::default()
+        }
+    }
+
+    #[test]
+    fn parses_terms_and_conditions() {
+        let filter = Filter::parse("BTC volume24>=1000 rsi_14<30 foo>1");
+        assert_eq!(filter.terms, vec!["btc".to_string(), "foo>1".to_string()]);
+        assert_eq!(filter.conditions, vec![
+            Condition { column: "volume24".to_string(), op: Op::Ge, value: 1000.0 },
+            Condition { column: "rsi_14".to_string(), op: Op::Lt, value: 30.0 },
+        ]);
+    }
+
+    #[test]
+    fn matches_symbol_or_name_case_insensitively() {
+        let filter = Filter::parse("bit");
+        assert!(filter.matches(&coin("BTC", "Bitcoin", None)));
+        assert!(!filter.matches(&coin("ETH", "Ethereum", None)));
+    }
+
+    #[test]
+    fn missing_values_never_match_conditions() {
+        let filter = Filter::parse("volume24>0");
+        assert!(filter.matches(&coin("BTC", "Bitcoin", Some(5.0))));
+        assert!(!filter.matches(&coin("BTC", "Bitcoin", None)));
+    }
+}
@@ -0,0 +1,200 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Price History Module
+//!
+//! This module defines the `History` struct, which records the price and volume of each
+//! cryptocurrency every time data is fetched. The recorded series feed the technical
+//! indicators in `indicators`, whose results are written back onto `CryptoData` so they
+//! can be displayed and sorted like any other column.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::cryptodata::CryptoData;
+use crate::indicators::{ema_sma_spread, rsi, volatility, z_score};
+
+/// Maximum number of samples kept per cryptocurrency; older samples are dropped first.
+pub const MAX_SAMPLES: usize = 200;
+
+/// Period of the fast EMA used for the crossover spread.
+pub const FAST_PERIOD: usize = 9;
+
+/// Period of the slow SMA used for the crossover spread.
+pub const SLOW_PERIOD: usize = 21;
+
+/// Period of the Relative Strength Index.
+pub const RSI_PERIOD: usize = 14;
+
+/// Number of returns used for rolling volatility.
+pub const VOLATILITY_WINDOW: usize = 20;
+
+/// Number of samples used for the volume z-score.
+pub const ZSCORE_WINDOW: usize = 20;
+
+/// A single recorded observation of a cryptocurrency.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+    /// Price in USD at the time of the fetch.
+    pub price: f64,
+
+    /// 24-hour trading volume at the time of the fetch.
+    pub volume: f64,
+}
+
+/// Recorded samples for every cryptocurrency, keyed by `series_key`, oldest first.
+#[derive(Default)]
+pub struct History {
+    series: HashMap<String, VecDeque<Sample>>,
+}
+
+impl History {
+    /// Appends a sample for every entry in `data` that has a key, a parsable price
+    /// and a volume. Entries missing any of these are skipped, as are entries whose key
+    /// already appeared earlier in `data`, so coins sharing a symbol never interleave.
+    ///
+    /// A sample equal to the last one recorded for its key is skipped too: the API
+    /// returns the same tickers until it updates them, and repeating them would make the
+    /// indicators depend on the refresh interval rather than on price changes.
+    pub fn record(&mut self, data: &[CryptoData]) {
+        let mut seen = HashSet::new();
+        for item in data {
+            let (Some(key), Some(price), Some(volume)) =
+                (series_key(item), item.numeric_value("price_usd"), item.volume24)
+            else {
+                continue;
+            };
+            if !seen.insert(key) {
+                continue;
+            }
+            let sample = Sample { price, volume };
+            let series = self.series.entry(key.to_string()).or_default();
+            if series.back() == Some(&sample) {
+                continue;
+            }
+            if series.len() == MAX_SAMPLES {
+                series.pop_front();
+            }
+            series.push_back(sample);
+        }
+    }
+
+    /// Returns the recorded samples for `key` (see `series_key`), oldest first.
+    pub fn samples(&self, key: &str) -> Option<&VecDeque<Sample>> {
+        self.series.get(key)
+    }
+
+    /// Computes the indicator fields of every entry in `data` from its recorded history.
+    ///
+    /// Indicators needing more samples than have been recorded are left as `None`.
+    pub fn apply_indicators(&self, data: &mut [CryptoData]) {
+        for item in data.iter_mut() {
+            let Some(series) = series_key(item).and_then(|key| self.samples(key)) else {
+                continue;
+            };
+            let prices: Vec<f64> = series.iter().map(|s| s.price).collect();
+            let volumes: Vec<f64> = series.iter().map(|s| s.volume).collect();
+            item.ema_sma_spread = ema_sma_spread(&prices, FAST_PERIOD, SLOW_PERIOD);
+            item.rsi_14 = rsi(&prices, RSI_PERIOD);
+            item.volatility = volatility(&prices, VOLATILITY_WINDOW);
+            item.volume_zscore = z_score(&volumes, ZSCORE_WINDOW);
+        }
+    }
+}
+
+/// Returns the key an entry's history is recorded under: the ticker API's id, or the
+/// symbol for sources that don't provide one.
+pub fn series_key(item: &CryptoData) -> Option<&str> {
+    item.id.as_deref().or(item.symbol.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(id: Option<&str>, symbol: &str, price: &str) -> CryptoData {
+        CryptoData {
+            id: id.map(str::to_string),
+            symbol: Some(symbol.to_string()),
+            price_usd: Some(price.to_string()),
+            volume24: Some(1000.0),
+            ..CryptoData::default()
+        }
+    }
+
+    #[test]
+    fn keeps_coins_sharing_a_symbol_apart() {
+        let mut history = History::default();
+        history.record(&[coin(Some("1"), "ABC", "10"), coin(Some("2"), "ABC", "0.01")]);
+        history.record(&[coin(Some("2"), "ABC", "0.02"), coin(Some("1"), "ABC", "11")]);
+
+        let prices = |key| history.samples(key).unwrap().iter().map(|s| s.price).collect::<Vec<_>>();
+        assert_eq!(prices("1"), vec![10.0, 11.0]);
+        assert_eq!(prices("2"), vec![0.01, 0.02]);
+        assert!(history.samples("ABC").is_none());
+    }
+
+    #[test]
+    fn skips_unchanged_tickers() {
+        let mut history = History::default();
+        let data = [coin(Some("1"), "ABC", "10")];
+        history.record(&data);
+        history.record(&data);
+        assert_eq!(history.samples("1").unwrap().len(), 1);
+
+        history.record(&[coin(Some("1"), "ABC", "11")]);
+        history.record(&data);
+        let prices: Vec<f64> = history.samples("1").unwrap().iter().map(|s| s.price).collect();
+        assert_eq!(prices, vec![10.0, 11.0, 10.0]);
+    }
+
+    #[test]
+    fn drops_the_oldest_samples_beyond_the_limit() {
+        let mut history = History::default();
+        for i in 0..MAX_SAMPLES + 5 {
+            history.record(&[coin(Some("1"), "ABC", &i.to_string())]);
+        }
+
+        let series = history.samples("1").unwrap();
+        assert_eq!(series.len(), MAX_SAMPLES);
+        assert_eq!(series.front().unwrap().price, 5.0);
+        assert_eq!(series.back().unwrap().price, (MAX_SAMPLES + 4) as f64);
+    }
+
+    #[test]
+    fn skips_entries_without_a_price_or_volume() {
+        let mut history = History::default();
+        let no_volume = CryptoData { volume24: None, ..coin(Some("1"), "ABC", "1") };
+        history.record(&[no_volume, coin(Some("2"), "DEF", "n/a")]);
+        assert!(history.samples("1").is_none());
+        assert!(history.samples("2").is_none());
+    }
+
+    #[test]
+    fn skips_repeated_symbols_without_ids() {
+        let mut history = History::default();
+        history.record(&[coin(None, "ABC", "10"), coin(None, "ABC", "0.01")]);
+
+        assert_eq!(history.samples("ABC").unwrap().len(), 1);
+        assert_eq!(history.samples("ABC").unwrap()[0].price, 10.0);
+    }
+}
@@ -0,0 +1,329 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Internationalization Module
+//!
+//! This module holds the message catalogue for every user-facing string. Strings are looked up by key with `tr`, or with `trf` for messages
+//! taking arguments, which fill `{}` placeholders in order.
+//!
+//! ## Fallback
+//!
+//! A key missing from the selected language's catalogue falls back to English, and a key
+//! missing from English falls back to the key itself, so an incomplete translation never
+//! leaves a blank in the interface. Column labels are the exception: their English text
+//! is the `label` in `columns::COLUMNS`, and other languages may override it with a
+//! `column.<key>` entry (see `column_label`).
+//!
+//! ## Adding a Language
+//!
+//! Add a `Language` variant, list it in `Language::ALL` with its code and native name,
+//! and give it a catalogue in `catalogue`.
+
+use serde::{Deserialize, Serialize};
+
+/// A language of the user interface.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// English, the fallback for missing translations.
+    #[default]
+    En,
+
+    /// German.
+    De,
+}
+
+impl Language {
+    /// Every language, in the order offered by the switcher.
+    pub const ALL: [Language; 2] = [Language::En, Language::De];
+
+    /// Returns the ISO 639-1 code, used for `<html lang>` and `<option>` values.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::De => "de",
+        }
+    }
+
+    /// Returns the language's name in that language, as shown in the switcher.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::De => "Deutsch",
+        }
+    }
+
+    /// Parses a language code or BCP 47 tag such as `"de-AT"` by its primary subtag.
+    pub fn from_code(code: &str) -> Option<Language> {
+        let primary = code.split('-').next().unwrap_or_default().to_ascii_lowercase();
+        Language::ALL.into_iter().find(|lang| lang.code() == primary)
+    }
+}
+
+/// Looks up `key` in `lang`'s catalogue only, without fallback.
+fn lookup(lang: Language, key: &str) -> Option<&'static str> {
+    catalogue(lang).iter().find(|(k, _)| *k == key).map(|(_, text)| *text)
+}
+
+/// Returns the text for `key` in `lang`, falling back to English and then to the key.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::i18n::{tr, Language};
+/// assert_eq!(tr(Language::De, "toolbar.columns"), "Spalten");
+/// ```
+pub fn tr(lang: Language, key: &'static str) -> &'static str {
+    lookup(lang, key).or_else(|| lookup(Language::En, key)).unwrap_or(key)
+}
+
+/// Returns the text for `key` in `lang` with each `{}` replaced by the next argument.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::i18n::{trf, Language};
+/// assert_eq!(trf(Language::En, "page.of", &[&2, &5]), "Page 2 of 5");
+/// ```
+pub fn trf(lang: Language, key: &'static str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut parts = tr(lang, key).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for (part, arg) in parts.zip(args.iter().map(Some).chain(std::iter::repeat(None))) {
+        if let Some(arg) = arg {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+/// Returns the label of a column in `lang`: its `column.<key>` translation if there is
+/// one, or else `english`, the label from the column definition.
+pub fn column_label(lang: Language, key: &str, english: &'static str) -> &'static str {
+    lookup(lang, &format!("column.{}", key)).unwrap_or(english)
+}
+
+/// Returns the catalogue of `lang`.
+fn catalogue(lang: Language) -> &'static [(&'static str, &'static str)] {
+    match lang {
+        Language::En => EN,
+        Language::De => DE,
+    }
+}
+
+/// English messages, the reference catalogue.
+static EN: &[(&str, &str)] = &[
+    ("app.title", "WBTek Crypto Screener"),
+    ("toolbar.about", "About"),
+    ("toolbar.export_csv", "Export all filtered rows (CSV)"),
+    ("toolbar.export_json", "Export all filtered rows (JSON)"),
+    ("toolbar.export_hint", "Exports every row matching the filter, on all pages"),
+    ("toolbar.copy", "Copy Selection"),
+    ("toolbar.columns", "Columns"),
+    ("toolbar.formatting", "Formatting"),
+    ("toolbar.heatmap", "Heatmap"),
+    ("toolbar.table", "Table"),
+    ("toolbar.quote", "Quote currency"),
+    ("toolbar.quote_pending", "Showing USD until {} rates load"),
+    ("toolbar.theme", "Theme"),
+    ("toolbar.language", "Language"),
+    ("theme.system", "System theme"),
+    ("theme.light", "Light theme"),
+    ("theme.dark", "Dark theme"),
+    ("filter.placeholder", "Filter, e.g. btc rsi_14>70 volume24>1000000"),
+    ("status.updated", "Updated {}"),
+    ("table.label", "Cryptocurrencies"),
+    ("error.fetch_data", "Failed to fetch data: {}"),
+    ("error.fetch_rates", "Failed to fetch exchange rates: {}"),
+    ("error.export", "Failed to export data: {}"),
+    ("error.copy", "Failed to copy cells: {}"),
+    ("announce.refreshed", "Data refreshed: {} coins"),
+    ("announce.sorted", "Sorted by {}, {}"),
+    ("announce.ascending", "ascending"),
+    ("announce.descending", "descending"),
+    ("announce.matches", "{} coins match the filter"),
+    ("chooser.reset", "Reset Columns"),
+    ("rules.add", "Add Rule"),
+    ("rules.reset", "Reset Rules"),
+    ("rules.remove", "Remove rule"),
+    ("rules.sign_color", "Green/red by sign"),
+    ("rules.color_scale", "Colour scale"),
+    ("rules.bold_above", "Bold above"),
+    ("rules.from", "from"),
+    ("rules.to", "to"),
+    ("rules.log", "log scale"),
+    ("rules.usd_hint", "Compared with the value in US dollars, whatever the quote currency"),
+    ("page.rows", "Rows per page"),
+    ("page.all", "All"),
+    ("page.first", "\u{00AB} First"),
+    ("page.prev", "\u{2039} Prev"),
+    ("page.next", "Next \u{203A}"),
+    ("page.last", "Last \u{00BB}"),
+    ("page.of", "Page {} of {}"),
+    ("page.showing", "Showing {}\u{2013}{} of {}"),
+    ("page.none", "No matching rows"),
+    ("stats.summary", ": count {} \u{00B7} sum {} \u{00B7} mean {} \u{00B7} median {} \u{00B7} min {} \u{00B7} max {}"),
+    ("heatmap.size", "Size by"),
+    ("heatmap.color", "Colour by"),
+    ("cards.sort", "Sort by"),
+    ("cards.ascending", "\u{2191} Ascending"),
+    ("cards.descending", "\u{2193} Descending"),
+    ("help.title", "Keyboard Shortcuts"),
+    ("help.move", "Move the cell cursor"),
+    ("help.toggle", "Highlight or unhighlight the cell under the cursor"),
+    ("help.sort", "Sort by the 1st\u{2013}9th or 10th visible column; again to reverse"),
+    ("help.search", "Focus the filter box"),
+    ("help.refresh", "Refresh the data"),
+    ("help.copy", "Copy highlighted cells"),
+    ("help.help", "Show or hide this help"),
+    ("help.space", "Space"),
+    ("help.close", "Close the About and help overlays"),
+    ("modal.close", "Close"),
+    ("about.intro", "A cryptocurrency analysis tool built with Rust and Yew, compiled to WebAssembly (WASM)."),
+    ("about.usage", "Click on header buttons to sort and resort data, and click on individual cells to highlight them."),
+    ("about.shortcuts", "Press ? for keyboard shortcuts."),
+    ("about.source", "Source and documentation:"),
+    ("about.homepage", "WBTek's Homepage:"),
+];
+
+/// German messages.
+static DE: &[(&str, &str)] = &[
+    ("toolbar.about", "Info"),
+    ("toolbar.export_csv", "Alle gefilterten Zeilen exportieren (CSV)"),
+    ("toolbar.export_json", "Alle gefilterten Zeilen exportieren (JSON)"),
+    ("toolbar.export_hint", "Exportiert alle Zeilen, die dem Filter entsprechen, auf allen Seiten"),
+    ("toolbar.copy", "Auswahl kopieren"),
+    ("toolbar.columns", "Spalten"),
+    ("toolbar.formatting", "Formatierung"),
+    ("toolbar.heatmap", "Heatmap"),
+    ("toolbar.table", "Tabelle"),
+    ("toolbar.quote", "Kurswährung"),
+    ("toolbar.quote_pending", "Anzeige in USD, bis die {}-Kurse geladen sind"),
+    ("toolbar.theme", "Design"),
+    ("toolbar.language", "Sprache"),
+    ("theme.system", "Systemdesign"),
+    ("theme.light", "Helles Design"),
+    ("theme.dark", "Dunkles Design"),
+    ("filter.placeholder", "Filtern, z. B. btc rsi_14>70 volume24>1000000"),
+    ("status.updated", "Aktualisiert {}"),
+    ("table.label", "Kryptowährungen"),
+    ("error.fetch_data", "Daten konnten nicht geladen werden: {}"),
+    ("error.fetch_rates", "Wechselkurse konnten nicht geladen werden: {}"),
+    ("error.export", "Export fehlgeschlagen: {}"),
+    ("error.copy", "Kopieren fehlgeschlagen: {}"),
+    ("announce.refreshed", "Daten aktualisiert: {} Coins"),
+    ("announce.sorted", "Sortiert nach {}, {}"),
+    ("announce.ascending", "aufsteigend"),
+    ("announce.descending", "absteigend"),
+    ("announce.matches", "{} Coins entsprechen dem Filter"),
+    ("chooser.reset", "Spalten zurücksetzen"),
+    ("rules.add", "Regel hinzufügen"),
+    ("rules.reset", "Regeln zurücksetzen"),
+    ("rules.remove", "Regel entfernen"),
+    ("rules.sign_color", "Grün/rot nach Vorzeichen"),
+    ("rules.color_scale", "Farbskala"),
+    ("rules.bold_above", "Fett ab"),
+    ("rules.from", "von"),
+    ("rules.to", "bis"),
+    ("rules.log", "logarithmisch"),
+    ("rules.usd_hint", "Wird mit dem Wert in US-Dollar verglichen, unabhängig von der Kurswährung"),
+    ("page.rows", "Zeilen pro Seite"),
+    ("page.all", "Alle"),
+    ("page.first", "\u{00AB} Erste"),
+    ("page.prev", "\u{2039} Zurück"),
+    ("page.next", "Weiter \u{203A}"),
+    ("page.last", "Letzte \u{00BB}"),
+    ("page.of", "Seite {} von {}"),
+    ("page.showing", "{}\u{2013}{} von {}"),
+    ("page.none", "Keine passenden Zeilen"),
+    ("stats.summary", ": Anzahl {} \u{00B7} Summe {} \u{00B7} Mittel {} \u{00B7} Median {} \u{00B7} Min. {} \u{00B7} Max. {}"),
+    ("heatmap.size", "Größe nach"),
+    ("heatmap.color", "Farbe nach"),
+    ("cards.sort", "Sortieren nach"),
+    ("cards.ascending", "\u{2191} Aufsteigend"),
+    ("cards.descending", "\u{2193} Absteigend"),
+    ("help.title", "Tastenkürzel"),
+    ("help.move", "Zellcursor bewegen"),
+    ("help.toggle", "Zelle unter dem Cursor markieren oder Markierung aufheben"),
+    ("help.sort", "Nach der 1.\u{2013}9. oder 10. sichtbaren Spalte sortieren; erneut zum Umkehren"),
+    ("help.search", "Filterfeld fokussieren"),
+    ("help.refresh", "Daten aktualisieren"),
+    ("help.copy", "Markierte Zellen kopieren"),
+    ("help.help", "Diese Hilfe ein- oder ausblenden"),
+    ("help.space", "Leertaste"),
+    ("help.close", "Info und Hilfe schließen"),
+    ("modal.close", "Schließen"),
+    ("about.intro", "Ein Analysewerkzeug für Kryptowährungen, entwickelt mit Rust und Yew und nach WebAssembly (WASM) kompiliert."),
+    ("about.usage", "Klicken Sie auf die Spaltenköpfe, um die Daten zu sortieren, und auf einzelne Zellen, um sie zu markieren."),
+    ("about.shortcuts", "Drücken Sie ? für Tastenkürzel."),
+    ("about.source", "Quellcode und Dokumentation:"),
+    ("about.homepage", "WBTek-Homepage:"),
+    ("column.price_usd", "Preis"),
+    ("column.percent_change_1h", "1 Std. %"),
+    ("column.percent_change_24h", "24 Std. %"),
+    ("column.percent_change_7d", "7 Tage %"),
+    ("column.volatility", "Volatilität %"),
+    ("column.volume_zscore", "Vol. z-Wert"),
+    ("column.volume24", "Volumen"),
+    ("column.market_cap_usd", "Marktkap."),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_english_then_key() {
+        assert_eq!(tr(Language::De, "toolbar.columns"), "Spalten");
+        assert_eq!(tr(Language::De, "app.title"), "WBTek Crypto Screener");
+        assert_eq!(tr(Language::De, "no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn fills_placeholders_in_order() {
+        assert_eq!(trf(Language::En, "page.of", &[&2, &5]), "Page 2 of 5");
+        assert_eq!(trf(Language::De, "page.showing", &[&1, &25, &"1.000"]), "1\u{2013}25 von 1.000");
+        assert_eq!(trf(Language::En, "page.of", &[&2]), "Page 2 of ");
+    }
+
+    #[test]
+    fn column_labels_fall_back_to_definition() {
+        assert_eq!(column_label(Language::De, "volume24", "Volume"), "Volumen");
+        assert_eq!(column_label(Language::En, "volume24", "Volume"), "Volume");
+    }
+
+    #[test]
+    fn parses_language_tags() {
+        assert_eq!(Language::from_code("de-AT"), Some(Language::De));
+        assert_eq!(Language::from_code("EN"), Some(Language::En));
+        assert_eq!(Language::from_code("fr"), None);
+    }
+
+    #[test]
+    fn every_translation_has_an_english_key() {
+        for (key, _) in DE.iter().filter(|(key, _)| !key.starts_with("column.")) {
+            assert!(lookup(Language::En, key).is_some(), "{key} is not in the English catalogue");
+        }
+    }
+}
@@ -0,0 +1,221 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Technical Indicators Module
+//!
+//! This module provides pure functions for computing technical indicators over a series
+//! of recorded values, oldest first. The screener uses them to derive extra columns from
+//! the price and volume history collected across fetches.
+//!
+//! ## Indicators
+//!
+//! - `sma`: Simple moving average of the most recent values.
+//! - `ema`: Exponential moving average, seeded with the SMA of the first period.
+//! - `rsi`: Relative Strength Index using Wilder's smoothing.
+//! - `volatility`: Sample standard deviation of percent returns over a rolling window.
+//! - `z_score`: Distance of the latest value from the window mean, in standard deviations.
+//! - `ema_sma_spread`: Percent spread of a fast EMA over a slow SMA, positive after a bullish crossover.
+//!
+//! Every function returns `None` when the series is too short for the requested period.
+
+/// Calculates the simple moving average of the last `period` values.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::indicators::sma;
+/// assert_eq!(sma(&[1.0, 2.0, 3.0, 4.0], 2), Some(3.5));
+/// ```
+pub fn sma(values: &[f64], period: usize) -> Option<f64> {
+    if period == 0 || values.len() < period {
+        return None;
+    }
+    Some(mean(&values[values.len() - period..]))
+}
+
+/// Calculates the exponential moving average of `values` over `period`.
+///
+/// The average is seeded with the SMA of the first `period` values and then smoothed
+/// with a factor of `2 / (period + 1)` over the remaining values.
+pub fn ema(values: &[f64], period: usize) -> Option<f64> {
+    if period == 0 || values.len() < period {
+        return None;
+    }
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let seed = mean(&values[..period]);
+    Some(values[period..].iter().fold(seed, |ema, v| v * alpha + ema * (1.0 - alpha)))
+}
+
+/// Calculates the Relative Strength Index of `values` over `period` using Wilder's smoothing.
+///
+/// Requires at least `period + 1` values. Returns `100.0` when there were gains but no
+/// losses, and the neutral `50.0` when the price did not move at all.
+pub fn rsi(values: &[f64], period: usize) -> Option<f64> {
+    if period == 0 || values.len() <= period {
+        return None;
+    }
+    let changes: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let n = period as f64;
+    let mut gain = changes[..period].iter().map(|c| c.max(0.0)).sum::<f64>() / n;
+    let mut loss = changes[..period].iter().map(|c| (-c).max(0.0)).sum::<f64>() / n;
+    for c in &changes[period..] {
+        gain = (gain * (n - 1.0) + c.max(0.0)) / n;
+        loss = (loss * (n - 1.0) + (-c).max(0.0)) / n;
+    }
+    if loss == 0.0 {
+        return Some(if gain == 0.0 { 50.0 } else { 100.0 });
+    }
+    Some(100.0 - 100.0 / (1.0 + gain / loss))
+}
+
+/// Calculates rolling volatility as the sample standard deviation of the last `period`
+/// percent returns.
+///
+/// Requires at least `period + 1` values. Returns `None` if a price in the window is zero.
+pub fn volatility(values: &[f64], period: usize) -> Option<f64> {
+    if period < 2 || values.len() <= period {
+        return None;
+    }
+    let window = &values[values.len() - period - 1..];
+    let returns: Option<Vec<f64>> = window
+        .windows(2)
+        .map(|w| if w[0] == 0.0 { None } else { Some((w[1] - w[0]) / w[0] * 100.0) })
+        .collect();
+    returns.map(|r| std_dev(&r))
+}
+
+/// Calculates the z-score of the latest value against the last `period` values.
+///
+/// Returns `None` if the window has no variation.
+pub fn z_score(values: &[f64], period: usize) -> Option<f64> {
+    if period < 2 || values.len() < period {
+        return None;
+    }
+    let window = &values[values.len() - period..];
+    let sd = std_dev(window);
+    if sd == 0.0 {
+        return None;
+    }
+    Some((window[period - 1] - mean(window)) / sd)
+}
+
+/// Calculates the spread of the `fast` EMA over the `slow` SMA as a percent of the SMA.
+///
+/// A change of sign between two calculations marks a crossover: positive values mean the
+/// EMA is above the SMA.
+pub fn ema_sma_spread(values: &[f64], fast: usize, slow: usize) -> Option<f64> {
+    let ema = ema(values, fast)?;
+    let sma = sma(values, slow)?;
+    if sma == 0.0 {
+        return None;
+    }
+    Some((ema - sma) / sma * 100.0)
+}
+
+/// Arithmetic mean of a non-empty slice.
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Sample standard deviation of a slice with at least two values.
+fn std_dev(values: &[f64]) -> f64 {
+    let m = mean(values);
+    let var = values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() as f64 - 1.0);
+    var.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Closing prices from the StockCharts ChartSchool RSI worked example.
+    const RSI_CLOSES: [f64; 15] = [
+        44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42,
+        45.84, 46.08, 45.89, 46.03, 45.61, 46.28, 46.28,
+    ];
+
+    /// Closing prices from the StockCharts ChartSchool moving average worked example.
+    const EMA_CLOSES: [f64; 30] = [
+        22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24, 22.29,
+        22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83, 23.95, 23.63,
+        23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68, 23.10, 22.40, 22.17,
+    ];
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("indicator should have a value");
+        assert!((actual - expected).abs() < 0.01, "expected {expected}, got {actual}");
+    }
+
+    #[test]
+    fn sma_of_last_period() {
+        assert_close(sma(&EMA_CLOSES, 10), 23.13);
+        assert_eq!(sma(&[1.0, 2.0], 3), None);
+        assert_eq!(sma(&[1.0, 2.0], 0), None);
+    }
+
+    #[test]
+    fn ema_matches_reference() {
+        assert_close(ema(&EMA_CLOSES, 10), 22.92);
+        assert_close(ema(&EMA_CLOSES[..10], 10), 22.22);
+        assert_eq!(ema(&EMA_CLOSES[..9], 10), None);
+    }
+
+    #[test]
+    fn rsi_matches_reference() {
+        assert_close(rsi(&RSI_CLOSES, 14), 70.46);
+        assert_eq!(rsi(&RSI_CLOSES[..14], 14), None);
+    }
+
+    #[test]
+    fn rsi_without_losses_is_100() {
+        assert_eq!(rsi(&[1.0, 2.0, 3.0, 4.0], 3), Some(100.0));
+    }
+
+    #[test]
+    fn rsi_of_a_flat_series_is_neutral() {
+        assert_eq!(rsi(&[5.0; 20], 14), Some(50.0));
+    }
+
+    #[test]
+    fn volatility_of_alternating_returns() {
+        assert_close(volatility(&[100.0, 110.0, 99.0, 108.9], 3), 11.55);
+        assert_eq!(volatility(&[0.0, 1.0, 2.0], 2), None);
+        assert_eq!(volatility(&[1.0, 2.0], 2), None);
+    }
+
+    #[test]
+    fn z_score_of_latest_value() {
+        assert_close(z_score(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0], 8), 1.87);
+        assert_eq!(z_score(&[3.0, 3.0, 3.0], 3), None);
+    }
+
+    #[test]
+    fn spread_sign_follows_trend() {
+        let rising: Vec<f64> = (1..=30).map(f64::from).collect();
+        let falling: Vec<f64> = rising.iter().rev().copied().collect();
+        assert!(ema_sma_spread(&rising, 9, 21).unwrap() > 0.0);
+        assert!(ema_sma_spread(&falling, 9, 21).unwrap() < 0.0);
+        assert_eq!(ema_sma_spread(&rising[..20], 9, 21), None);
+    }
+}
@@ -0,0 +1,67 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # WBTek Crypto Screener Core
+//!
+//! This crate holds the platform-independent part of the WBTek Crypto Screener: the data
+//! model and its parsing, sorting, filtering, number formatting, indicators, statistics,
+//! exports and the message catalogue. It has no browser or UI dependencies, so it can be
+//! tested with plain `cargo test` and shared by every front-end; the Yew web app in the
+//! parent crate adds only rendering, networking and browser storage on top of it.
+//!
+//! ## Module Overview
+//!
+//! - `columns`: Defines the screener's columns once (keys, labels, widths and display functions) and the user's column layout.
+//! - `cryptodata`: Defines the `CryptoData` struct, which models cryptocurrency data fetched from an external API.
+//! - `currency`: Converts USD values into the selected fiat or crypto quote currency.
+//! - `export`: Renders rows as CSV or JSON, and highlighted cells as a tab-separated grid.
+//! - `filter`: Parses filter queries and decides which rows are shown.
+//! - `history`: Records price and volume samples across fetches and applies indicators.
+//! - `i18n`: The message catalogue and translation fallback.
+//! - `indicators`: Pure technical indicator math (SMA, EMA, RSI, volatility, z-score).
+//! - `numformat`: Locale-aware number formatting for prices, volumes and percentages.
+//! - `pagination`: Splits the filtered rows into pages.
+//! - `rules`: Conditional formatting rules that style cells by value.
+//! - `selection`: Toggles highlighted cells and rows.
+//! - `snapshot`: Reads recorded ticker payloads, including inline `data:` URLs.
+//! - `sort`: Provides utilities for sorting data based on various criteria.
+//! - `stats`: Computes count, sum, mean, median, min and max of highlighted numeric cells.
+//! - `treemap`: Squarified treemap layout used by the heatmap view.
+
+pub mod columns;
+pub mod cryptodata;
+pub mod currency;
+pub mod export;
+pub mod filter;
+pub mod history;
+pub mod i18n;
+pub mod indicators;
+pub mod numformat;
+pub mod pagination;
+pub mod rules;
+pub mod selection;
+pub mod snapshot;
+pub mod sort;
+pub mod stats;
+pub mod treemap;
@@ -0,0 +1,288 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Number Formatting Module
+//!
+//! This module formats numbers for display in the screener. Prices keep a fixed number of
+//! significant digits so both large and micro-cap prices stay readable, volumes and market
+//! caps use compact notation (e.g. `1.2B`, `340M`), and percentages carry an explicit `+`
+//! sign when positive. Every function takes the thousands and decimal separators to use;
+//! the web front-end derives them from the selected language.
+//!
+//! ## Key Functions
+//!
+//! - `format_significant`: Formats a value with at least four significant digits.
+//! - `format_compact`: Formats a value with a K/M/B/T suffix.
+//! - `format_percent`: Formats a signed percentage with two decimals.
+//! - `format_fixed`: Formats a value with a fixed number of decimals and grouped thousands.
+//! - `NumberLocale`: The separators used for display.
+
+/// Minimum number of significant digits shown by `format_significant`.
+const SIGNIFICANT_DIGITS: i32 = 4;
+
+/// Upper bound on the decimals shown by `format_significant`; smaller values switch to
+/// scientific notation.
+const MAX_DECIMALS: usize = 10;
+
+/// Suffixes used by `format_compact`, with their magnitudes.
+const COMPACT_UNITS: [(f64, &str); 4] = [(1e3, "K"), (1e6, "M"), (1e9, "B"), (1e12, "T")];
+
+/// The thousands and decimal separators of a locale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberLocale {
+    /// Separator between groups of three integer digits (e.g. "," in en-US).
+    pub group: String,
+
+    /// Separator between the integer and fractional parts (e.g. "." in en-US).
+    pub decimal: String,
+}
+
+impl Default for NumberLocale {
+    /// The en-US separators, used when a locale's separators can't be determined.
+    fn default() -> Self {
+        NumberLocale { group: ",".to_string(), decimal: ".".to_string() }
+    }
+}
+
+impl NumberLocale {
+    /// Derives the separators from how a locale renders `12345.6`.
+    ///
+    /// Returns `None` if the sample does not have the expected shape, for example when
+    /// the locale uses non-Latin digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crypto_screener_core::numformat::NumberLocale;
+    /// let de = NumberLocale::from_sample("12.345,6").unwrap();
+    /// assert_eq!((de.group.as_str(), de.decimal.as_str()), (".", ","));
+    /// ```
+    pub fn from_sample(sample: &str) -> Option<Self> {
+        let rest = sample.strip_prefix("12")?;
+        let split = rest.find("345")?;
+        let group = &rest[..split];
+        let decimal = rest[split + 3..].strip_suffix('6')?;
+        if decimal.is_empty() || group.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some(NumberLocale { group: group.to_string(), decimal: decimal.to_string() })
+    }
+}
+
+/// Formats `value` with `decimals` fractional digits and grouped thousands.
+///
+/// Values that round to zero are never shown with a minus sign. Non-finite values are
+/// rendered as Rust displays them.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::numformat::{format_fixed, NumberLocale};
+/// assert_eq!(format_fixed(-1234567.891, 2, &NumberLocale::default()), "-1,234,567.89");
+/// ```
+pub fn format_fixed(value: f64, decimals: usize, locale: &NumberLocale) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let digits = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits.as_str(), None),
+    };
+
+    let mut out = String::new();
+    if value < 0.0 && digits.chars().any(|c| ('1'..='9').contains(&c)) {
+        out.push('-');
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(3) {
+            out.push_str(&locale.group);
+        }
+        out.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        out.push_str(&locale.decimal);
+        out.push_str(fraction);
+    }
+    out
+}
+
+/// Formats `value` with at least two decimals and at least four significant digits,
+/// so `67012.5` renders as `67,012.50` and `0.000012346` as `0.00001235`. Values that would
+/// need more than `MAX_DECIMALS` decimals are shown in scientific notation instead, e.g.
+/// `1.235e-12`, so micro-cap prices never collapse to zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::numformat::{format_significant, NumberLocale};
+/// assert_eq!(format_significant(1.23456, &NumberLocale::default()), "1.235");
+/// ```
+pub fn format_significant(value: f64, locale: &NumberLocale) -> String {
+    let decimals = if value.is_finite() && value != 0.0 {
+        let magnitude = value.abs().log10().floor() as i32;
+        (SIGNIFICANT_DIGITS - 1 - magnitude).max(2) as usize
+    } else {
+        2
+    };
+    if decimals <= MAX_DECIMALS {
+        return format_fixed(value, decimals, locale);
+    }
+    let scientific = format!("{:.*e}", SIGNIFICANT_DIGITS as usize - 1, value);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let mantissa: f64 = mantissa.parse().unwrap_or_default();
+    format!("{}e{}", format_fixed(mantissa, SIGNIFICANT_DIGITS as usize - 1, locale), exponent)
+}
+
+/// Formats `value` in compact notation: one decimal below 100 of a unit and none above,
+/// e.g. `1.2B` or `340M`. Values below a thousand are shown with two decimals.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::numformat::{format_compact, NumberLocale};
+/// assert_eq!(format_compact(999_960_000.0, &NumberLocale::default()), "1.0B");
+/// ```
+pub fn format_compact(value: f64, locale: &NumberLocale) -> String {
+    let abs = value.abs();
+    if !value.is_finite() || abs < COMPACT_UNITS[0].0 {
+        return format_fixed(value, 2, locale);
+    }
+
+    let mut unit = COMPACT_UNITS.iter().rposition(|(size, _)| abs >= *size).unwrap_or(0);
+    loop {
+        let scaled = value / COMPACT_UNITS[unit].0;
+        let decimals = if scaled.abs() < 99.95 { 1 } else { 0 };
+        // Rounding may carry into the next unit, e.g. 999.96M becomes 1.0B.
+        if scaled.abs() >= 999.5 && unit + 1 < COMPACT_UNITS.len() {
+            unit += 1;
+            continue;
+        }
+        return format!("{}{}", format_fixed(scaled, decimals, locale), COMPACT_UNITS[unit].1);
+    }
+}
+
+/// Formats a percentage with two decimals and a `+` sign when it is positive after
+/// rounding.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::numformat::{format_percent, NumberLocale};
+/// assert_eq!(format_percent(1.234, &NumberLocale::default()), "+1.23");
+/// ```
+pub fn format_percent(value: f64, locale: &NumberLocale) -> String {
+    let formatted = format_fixed(value, 2, locale);
+    if value > 0.0 && formatted.chars().any(|c| ('1'..='9').contains(&c)) {
+        format!("+{}", formatted)
+    } else {
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn en() -> NumberLocale {
+        NumberLocale::default()
+    }
+
+    fn de() -> NumberLocale {
+        NumberLocale { group: ".".to_string(), decimal: ",".to_string() }
+    }
+
+    #[test]
+    fn fixed_groups_thousands() {
+        assert_eq!(format_fixed(0.0, 2, &en()), "0.00");
+        assert_eq!(format_fixed(999.0, 0, &en()), "999");
+        assert_eq!(format_fixed(1000.0, 0, &en()), "1,000");
+        assert_eq!(format_fixed(1234567.891, 2, &en()), "1,234,567.89");
+        assert_eq!(format_fixed(-1234567.891, 2, &de()), "-1.234.567,89");
+    }
+
+    #[test]
+    fn fixed_drops_sign_of_negative_zero() {
+        assert_eq!(format_fixed(-0.001, 2, &en()), "0.00");
+        assert_eq!(format_fixed(-0.0, 2, &en()), "0.00");
+    }
+
+    #[test]
+    fn fixed_passes_through_non_finite() {
+        assert_eq!(format_fixed(f64::NAN, 2, &en()), "NaN");
+        assert_eq!(format_fixed(f64::INFINITY, 2, &en()), "inf");
+    }
+
+    #[test]
+    fn significant_keeps_precision_for_small_prices() {
+        assert_eq!(format_significant(67000.123456, &en()), "67,000.12");
+        assert_eq!(format_significant(1.23456, &en()), "1.235");
+        assert_eq!(format_significant(0.5, &en()), "0.5000");
+        assert_eq!(format_significant(0.000012346, &en()), "0.00001235");
+        assert_eq!(format_significant(1.23456e-7, &en()), "0.0000001235");
+        assert_eq!(format_significant(1.23456e-9, &en()), "1.235e-9");
+        assert_eq!(format_significant(1e-15, &en()), "1.000e-15");
+        assert_eq!(format_significant(1.23456e-12, &de()), "1,235e-12");
+        assert_eq!(format_significant(-9.99996e-11, &en()), "-1.000e-10");
+        assert_eq!(format_significant(0.0, &en()), "0.00");
+        assert_eq!(format_significant(-0.0123456, &de()), "-0,01235");
+    }
+
+    #[test]
+    fn compact_uses_suffixes() {
+        assert_eq!(format_compact(999.994, &en()), "999.99");
+        assert_eq!(format_compact(1000.0, &en()), "1.0K");
+        assert_eq!(format_compact(340_000_000.0, &en()), "340M");
+        assert_eq!(format_compact(1_234_000_000.0, &en()), "1.2B");
+        assert_eq!(format_compact(-12_500_000.0, &de()), "-12,5M");
+        assert_eq!(format_compact(2.5e15, &en()), "2,500T");
+    }
+
+    #[test]
+    fn compact_carries_rounding_into_next_unit() {
+        assert_eq!(format_compact(99_960.0, &en()), "100K");
+        assert_eq!(format_compact(999_600.0, &en()), "1.0M");
+        assert_eq!(format_compact(999_960_000.0, &en()), "1.0B");
+    }
+
+    #[test]
+    fn percent_signs_positive_values() {
+        assert_eq!(format_percent(1.234, &en()), "+1.23");
+        assert_eq!(format_percent(-1.234, &en()), "-1.23");
+        assert_eq!(format_percent(0.0, &en()), "0.00");
+        assert_eq!(format_percent(0.001, &en()), "0.00");
+        assert_eq!(format_percent(1234.5, &de()), "+1.234,50");
+    }
+
+    #[test]
+    fn locale_from_sample() {
+        assert_eq!(NumberLocale::from_sample("12,345.6"), Some(en()));
+        assert_eq!(NumberLocale::from_sample("12.345,6"), Some(de()));
+        let fr = NumberLocale::from_sample("12\u{202F}345,6").unwrap();
+        assert_eq!((fr.group.as_str(), fr.decimal.as_str()), ("\u{202F}", ","));
+        let es = NumberLocale::from_sample("12345,6").unwrap();
+        assert_eq!((es.group.as_str(), es.decimal.as_str()), ("", ","));
+        assert_eq!(NumberLocale::from_sample("١٢٬٣٤٥٫٦"), None);
+    }
+}
@@ -0,0 +1,87 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Pagination Module
+//!
+//! This module supports classic page-based navigation as an alternative to scrolling.
+//! Pages are taken from the filtered, sorted rows; this module does the arithmetic, and
+//! the front-end decides where the current page is kept.
+
+use std::ops::Range;
+
+/// Page sizes offered to the user.
+pub const PAGE_SIZES: [usize; 3] = [25, 50, 100];
+
+/// Returns the number of pages needed for `total` rows, at least one.
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Returns the range of row indices on the 0-based `page`, clamping `page` to the last page.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::pagination::page_range;
+/// assert_eq!(page_range(1, 50, 120), 50..100);
+/// assert_eq!(page_range(9, 50, 120), 100..120);
+/// ```
+pub fn page_range(page: usize, page_size: usize, total: usize) -> Range<usize> {
+    let page = page.min(page_count(total, page_size) - 1);
+    let start = page * page_size;
+    start.min(total)..(start + page_size).min(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_pages() {
+        assert_eq!(page_count(120, 50), 3);
+        assert_eq!(page_count(100, 50), 2);
+        assert_eq!(page_count(1, 50), 1);
+    }
+
+    #[test]
+    fn empty_data_has_one_page() {
+        assert_eq!(page_count(0, 50), 1);
+        assert_eq!(page_range(0, 50, 0), 0..0);
+        assert_eq!(page_range(3, 50, 0), 0..0);
+    }
+
+    #[test]
+    fn page_size_zero_is_treated_as_one() {
+        assert_eq!(page_count(5, 0), 5);
+        assert_eq!(page_range(2, 0, 5), 0..0);
+        assert_eq!(page_count(0, 0), 1);
+    }
+
+    #[test]
+    fn clamps_to_the_last_page() {
+        assert_eq!(page_range(0, 50, 120), 0..50);
+        assert_eq!(page_range(2, 50, 120), 100..120);
+        assert_eq!(page_range(usize::MAX, 50, 120), 100..120);
+    }
+}
@@ -0,0 +1,267 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Conditional Formatting Rules Module
+//!
+//! This module defines user-configurable rules that style table cells based on their
+//! values, such as green/red text by sign, colour scales, or bold text above a threshold.
+//! Rules are evaluated per cell into CSS declarations; front-ends decide how to combine
+//! them with their own styles and where to keep the rule set.
+
+use serde::{Deserialize, Serialize};
+use crate::cryptodata::CryptoData;
+
+/// Text colour for positive values in `Format::SignColor`, from the theme.
+const POSITIVE_COLOR: &str = "var(--cs-positive)";
+
+/// Text colour for negative values in `Format::SignColor`, from the theme.
+const NEGATIVE_COLOR: &str = "var(--cs-negative)";
+
+/// Strongest share of the colour in a `Format::ColorScale` background, in percent.
+const MAX_SCALE_PERCENT: f64 = 63.0;
+
+/// The styling applied by a rule.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Format {
+    /// Colours text green for positive values and red for negative values.
+    SignColor,
+
+    /// Shades the background with `color`, from transparent at `min` to strongest at `max`.
+    /// With `log` set, values are compared on a logarithmic scale, which suits volumes.
+    /// `color` can be any CSS colour, since it is mixed with `color-mix()`.
+    ColorScale { min: f64, max: f64, color: String, log: bool },
+
+    /// Makes text bold when the value is above `threshold`.
+    BoldAbove { threshold: f64 },
+}
+
+impl Format {
+    /// Kinds of format offered in the settings panel, with the message keys of their labels.
+    pub const KINDS: [(&'static str, &'static str); 3] = [
+        ("sign_color", "rules.sign_color"),
+        ("color_scale", "rules.color_scale"),
+        ("bold_above", "rules.bold_above"),
+    ];
+
+    /// Returns the identifier of this format's kind, as listed in `KINDS`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Format::SignColor => "sign_color",
+            Format::ColorScale { .. } => "color_scale",
+            Format::BoldAbove { .. } => "bold_above",
+        }
+    }
+
+    /// Returns a format of the given kind with default parameters.
+    pub fn for_kind(kind: &str) -> Format {
+        match kind {
+            "color_scale" => Format::ColorScale {
+                min: 0.0,
+                max: 100.0,
+                color: "#4682b4".to_string(),
+                log: false,
+            },
+            "bold_above" => Format::BoldAbove { threshold: 0.0 },
+            _ => Format::SignColor,
+        }
+    }
+
+    /// Returns the CSS declarations for a cell holding `value`, or `None` if the format
+    /// does not apply.
+    fn style(&self, value: f64) -> Option<String> {
+        match self {
+            Format::SignColor if value > 0.0 => Some(format!("color: {};", POSITIVE_COLOR)),
+            Format::SignColor if value < 0.0 => Some(format!("color: {};", NEGATIVE_COLOR)),
+            Format::SignColor => None,
+            Format::ColorScale { min, max, color, log } => {
+                let scale = |v: f64| if *log { v.max(f64::MIN_POSITIVE).log10() } else { v };
+                let (low, high) = (scale(*min), scale(*max));
+                if high <= low {
+                    return None;
+                }
+                let t = ((scale(value) - low) / (high - low)).clamp(0.0, 1.0);
+                let percent = (t * MAX_SCALE_PERCENT).round();
+                Some(format!("background-color: color-mix(in srgb, {} {}%, transparent);", color, percent))
+            }
+            Format::BoldAbove { threshold } if value > *threshold => {
+                Some("font-weight: bold;".to_string())
+            }
+            Format::BoldAbove { .. } => None,
+        }
+    }
+}
+
+/// A conditional formatting rule for one column.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Rule {
+    /// Key of the column the rule applies to (e.g. "percent_change_24h").
+    pub column: String,
+
+    /// The styling to apply.
+    pub format: Format,
+
+    /// Whether the rule is currently applied.
+    pub enabled: bool,
+}
+
+/// The ordered list of formatting rules.
+///
+/// When several rules set the same CSS property on a cell, the later rule wins.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RuleSet {
+    pub rules: Vec<Rule>,
+}
+
+impl Default for RuleSet {
+    /// Green/red percent changes and a logarithmic colour scale on volume.
+    fn default() -> Self {
+        let sign = |column: &str| Rule {
+            column: column.to_string(),
+            format: Format::SignColor,
+            enabled: true,
+        };
+        RuleSet {
+            rules: vec![
+                sign("percent_change_1h"),
+                sign("percent_change_24h"),
+                sign("percent_change_7d"),
+                Rule {
+                    column: "volume24".to_string(),
+                    format: Format::ColorScale {
+                        min: 1e5,
+                        max: 1e10,
+                        color: "#4682b4".to_string(),
+                        log: true,
+                    },
+                    enabled: true,
+                },
+            ],
+        }
+    }
+}
+
+impl RuleSet {
+    /// Returns the CSS declarations of every enabled rule for `column` that applies to
+    /// `item`'s value, in rule order. Cells without a numeric value are not styled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crypto_screener_core::cryptodata::CryptoData;
+    /// # use crypto_screener_core::rules::RuleSet;
+    /// let crypto = CryptoData { percent_change_1h: Some("-1.5".to_string()), ..Default::default() };
+    /// assert_eq!(RuleSet::default().cell_style(&crypto, "percent_change_1h"), "color: var(--cs-negative);");
+    /// ```
+    pub fn cell_style(&self, item: &CryptoData, column: &str) -> String {
+        let Some(value) = item.numeric_value(column) else {
+            return String::new();
+        };
+        self.rules
+            .iter()
+            .filter(|rule| rule.enabled && rule.column == column)
+            .filter_map(|rule| rule.format.style(value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(column: &str, format: Format) -> Rule {
+        Rule { column: column.to_string(), format, enabled: true }
+    }
+
+    fn scale(log: bool) -> Format {
+        Format::ColorScale { min: 10.0, max: 1000.0, color: "red".to_string(), log }
+    }
+
+    fn coin(price: &str) -> CryptoData {
+        CryptoData {
+            name: Some("Bitcoin".to_string()),
+            price_usd: Some(price.to_string()),
+            ..CryptoData::default()
+        }
+    }
+
+    #[test]
+    fn sign_colour_leaves_zero_alone() {
+        assert_eq!(Format::SignColor.style(2.0).as_deref(), Some("color: var(--cs-positive);"));
+        assert_eq!(Format::SignColor.style(-2.0).as_deref(), Some("color: var(--cs-negative);"));
+        assert_eq!(Format::SignColor.style(0.0), None);
+    }
+
+    #[test]
+    fn bold_only_strictly_above_the_threshold() {
+        let bold = Format::BoldAbove { threshold: 100.0 };
+        assert_eq!(bold.style(100.0), None);
+        assert_eq!(bold.style(100.5).as_deref(), Some("font-weight: bold;"));
+    }
+
+    #[test]
+    fn colour_scale_interpolates_and_clamps() {
+        let mix = |percent: u32| Some(format!("background-color: color-mix(in srgb, red {}%, transparent);", percent));
+        let linear = scale(false);
+        assert_eq!(linear.style(-5.0), mix(0));
+        assert_eq!(linear.style(10.0), mix(0));
+        assert_eq!(linear.style(505.0), mix(32));
+        assert_eq!(linear.style(1000.0), mix(63));
+        assert_eq!(linear.style(1e9), mix(63));
+
+        let log = scale(true);
+        assert_eq!(log.style(100.0), mix(32));
+        assert_eq!(log.style(0.0), mix(0));
+    }
+
+    #[test]
+    fn colour_scale_needs_a_rising_range() {
+        let empty = Format::ColorScale { min: 5.0, max: 5.0, color: "red".to_string(), log: false };
+        assert_eq!(empty.style(5.0), None);
+    }
+
+    #[test]
+    fn later_rules_come_last() {
+        let rules = RuleSet {
+            rules: vec![rule("price_usd", Format::BoldAbove { threshold: 0.0 }), rule("price_usd", Format::SignColor)],
+        };
+        assert_eq!(rules.cell_style(&coin("5"), "price_usd"), "font-weight: bold;color: var(--cs-positive);");
+    }
+
+    #[test]
+    fn skips_disabled_and_other_columns() {
+        let mut disabled = rule("price_usd", Format::SignColor);
+        disabled.enabled = false;
+        let rules = RuleSet { rules: vec![disabled, rule("volume24", Format::SignColor)] };
+        assert_eq!(rules.cell_style(&coin("5"), "price_usd"), "");
+        assert_eq!(RuleSet { rules: vec![] }.cell_style(&coin("5"), "price_usd"), "");
+    }
+
+    #[test]
+    fn text_and_missing_values_are_not_styled() {
+        let rules = RuleSet { rules: vec![rule("name", Format::SignColor), rule("price_usd", Format::SignColor)] };
+        assert_eq!(rules.cell_style(&coin("5"), "name"), "");
+        assert_eq!(rules.cell_style(&coin("n/a"), "price_usd"), "");
+    }
+}
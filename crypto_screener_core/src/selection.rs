@@ -0,0 +1,130 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Selection Module
+//!
+//! This module provides helper functions for managing the selection state of cells in the
+//! screener. Cells are identified by `(id, column)` pairs, and can be toggled one at a
+//! time or a whole row at once.
+
+use std::collections::HashSet;
+
+/// Toggles the selection state of a table cell.
+///
+/// Given a unique identifier (`id`) and a column name (`column`), this function either adds
+/// the cell to the set of `selected_cells` if it is not already present, or removes it if
+/// it is currently selected.
+///
+/// # Parameters
+///
+/// - `selected_cells`: A mutable reference to a `HashSet` containing the selected cells, represented
+///   as `(id, column)` pairs.
+/// - `id`: A unique identifier for the row, typically the symbol or another unique value.
+/// - `column`: The column name associated with the cell to toggle.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use crypto_screener_core::selection::toggle_cell_selection;
+/// let mut selected_cells = HashSet::new();
+/// toggle_cell_selection(&mut selected_cells, "BTC".to_string(), "price_usd".to_string());
+/// assert!(selected_cells.contains(&("BTC".to_string(), "price_usd".to_string())));
+/// ```
+pub fn toggle_cell_selection(
+    selected_cells: &mut HashSet<(String, String)>,
+    id: String,
+    column: String,
+) {
+    let cell = (id.clone(), column.clone());
+    if selected_cells.contains(&cell) {
+        selected_cells.remove(&cell);
+    } else {
+        selected_cells.insert(cell.clone());
+    }
+}
+
+/// Toggles the selection state of a whole row.
+///
+/// If every cell of the row identified by `id` (one per entry in `columns`) is selected,
+/// all of them are deselected; otherwise all of them are selected.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use crypto_screener_core::selection::toggle_row_selection;
+/// let mut selected_cells = HashSet::new();
+/// toggle_row_selection(&mut selected_cells, "BTC", &["symbol", "price_usd"]);
+/// assert!(selected_cells.contains(&("BTC".to_string(), "price_usd".to_string())));
+/// ```
+pub fn toggle_row_selection(
+    selected_cells: &mut HashSet<(String, String)>,
+    id: &str,
+    columns: &[&str],
+) {
+    let cells: Vec<(String, String)> = columns
+        .iter()
+        .map(|column| (id.to_string(), column.to_string()))
+        .collect();
+    if cells.iter().all(|cell| selected_cells.contains(cell)) {
+        for cell in &cells {
+            selected_cells.remove(cell);
+        }
+    } else {
+        selected_cells.extend(cells);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(id: &str, column: &str) -> (String, String) {
+        (id.to_string(), column.to_string())
+    }
+
+    #[test]
+    fn toggles_a_cell_on_and_off() {
+        let mut selected = HashSet::new();
+        toggle_cell_selection(&mut selected, "BTC".to_string(), "name".to_string());
+        assert_eq!(selected, HashSet::from([cell("BTC", "name")]));
+        toggle_cell_selection(&mut selected, "BTC".to_string(), "name".to_string());
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn selects_the_rest_of_a_partly_selected_row() {
+        let mut selected = HashSet::from([cell("BTC", "name"), cell("ETH", "name")]);
+        toggle_row_selection(&mut selected, "BTC", &["symbol", "name"]);
+        assert_eq!(selected, HashSet::from([cell("BTC", "symbol"), cell("BTC", "name"), cell("ETH", "name")]));
+    }
+
+    #[test]
+    fn deselects_a_fully_selected_row() {
+        let mut selected = HashSet::from([cell("BTC", "symbol"), cell("BTC", "name"), cell("ETH", "name")]);
+        toggle_row_selection(&mut selected, "BTC", &["symbol", "name"]);
+        assert_eq!(selected, HashSet::from([cell("ETH", "name")]));
+    }
+}
@@ -0,0 +1,193 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Snapshot Module
+//!
+//! This module reads recorded ticker payloads, so a screen can be replayed offline or in
+//! tests instead of fetching live data. A snapshot is the JSON body of a ticker API
+//! response, saved as is; it goes through the same `parse_tickers` as a live response.
+//!
+//! In the browser a snapshot can be passed inline as a `data:` URL, such as
+//! `data:application/json,{"data":[...]}` or its base64 form, which `decode_data_url`
+//! turns back into the JSON text.
+//!
+//! ## Key Functions
+//!
+//! - `parse_snapshot`: Parses the text of a recorded ticker response.
+//! - `is_data_url` / `decode_data_url`: Recognize and decode `data:` URLs.
+
+use std::fmt;
+use serde_json::Value;
+use crate::cryptodata::{parse_tickers, CryptoData};
+
+/// Why a snapshot could not be read.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The `data:` URL is malformed, with a description of the problem.
+    DataUrl(&'static str),
+
+    /// The payload is not valid JSON.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::DataUrl(problem) => write!(f, "invalid data URL: {}", problem),
+            SnapshotError::Json(err) => write!(f, "invalid snapshot JSON: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Parses the text of a recorded ticker response, exactly as a live response is parsed.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::snapshot::parse_snapshot;
+/// let data = parse_snapshot(r#"{"data": [{"symbol": "BTC"}]}"#).unwrap();
+/// assert_eq!(data[0].symbol.as_deref(), Some("BTC"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `SnapshotError::Json` if the text is not valid JSON.
+pub fn parse_snapshot(text: &str) -> Result<Vec<CryptoData>, SnapshotError> {
+    let payload: Value = serde_json::from_str(text).map_err(SnapshotError::Json)?;
+    Ok(parse_tickers(&payload))
+}
+
+/// Whether `url` is a `data:` URL, which carries its payload inline.
+pub fn is_data_url(url: &str) -> bool {
+    url.get(..5).is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+}
+
+/// Returns the payload of a `data:` URL as text.
+///
+/// Both percent-encoded (`data:application/json,%7B...`) and base64
+/// (`data:application/json;base64,eyJ...`) payloads are accepted; the media type is
+/// ignored.
+///
+/// # Errors
+///
+/// Returns `SnapshotError::DataUrl` if `url` is not a `data:` URL, has no `,` before the
+/// payload, or the payload does not decode to UTF-8 text.
+pub fn decode_data_url(url: &str) -> Result<String, SnapshotError> {
+    if !is_data_url(url) {
+        return Err(SnapshotError::DataUrl("not a data: URL"));
+    }
+    let (header, payload) = url[5..]
+        .split_once(',')
+        .ok_or(SnapshotError::DataUrl("missing ',' before the payload"))?;
+    let bytes = percent_decode(payload).ok_or(SnapshotError::DataUrl("bad percent-encoding"))?;
+    let bytes = if header.to_ascii_lowercase().ends_with(";base64") {
+        base64_decode(&bytes).ok_or(SnapshotError::DataUrl("bad base64 payload"))?
+    } else {
+        bytes
+    };
+    String::from_utf8(bytes).map_err(|_| SnapshotError::DataUrl("payload is not UTF-8"))
+}
+
+/// Decodes `%XX` escapes, returning `None` if an escape is not two hex digits.
+fn percent_decode(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Some(decoded)
+}
+
+/// Decodes standard or URL-safe base64, ignoring whitespace and padding. Returns `None`
+/// on any other character.
+fn base64_decode(encoded: &[u8]) -> Option<Vec<u8>> {
+    let sextet = |c: u8| match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    };
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for &c in encoded.iter().filter(|c| !c.is_ascii_whitespace() && **c != b'=') {
+        buffer = (buffer << 6) | sextet(c)? as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &str = r#"{"data":[{"symbol":"BTC","volume24":1.5}]}"#;
+
+    #[test]
+    fn parses_like_a_live_response() {
+        let data = parse_snapshot(PAYLOAD).unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].symbol.as_deref(), Some("BTC"));
+        assert_eq!(data[0].volume24, Some(1.5));
+        assert!(parse_snapshot(r#"{"error":"rate limited"}"#).unwrap().is_empty());
+        assert!(matches!(parse_snapshot("{"), Err(SnapshotError::Json(_))));
+    }
+
+    #[test]
+    fn decodes_percent_encoded_data_urls() {
+        let url = "data:application/json,%7B%22data%22%3A%5B%5D%7D";
+        assert!(is_data_url(url));
+        assert_eq!(decode_data_url(url).unwrap(), r#"{"data":[]}"#);
+        assert_eq!(decode_data_url(&format!("DATA:,{}", PAYLOAD)).unwrap(), PAYLOAD);
+    }
+
+    #[test]
+    fn decodes_base64_data_urls() {
+        let url = "data:application/json;base64,eyJkYXRhIjpbXX0=";
+        assert_eq!(decode_data_url(url).unwrap(), r#"{"data":[]}"#);
+    }
+
+    #[test]
+    fn rejects_malformed_data_urls() {
+        assert!(!is_data_url("https://api.coinlore.net/api/tickers/"));
+        assert!(decode_data_url("data:application/json").is_err());
+        assert!(decode_data_url("data:,%zz").is_err());
+        assert!(decode_data_url("data:;base64,!!!").is_err());
+    }
+}
@@ -0,0 +1,416 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Sorting Module
+//!
+//! This module provides sorting functionality for the cryptocurrency data in the WBTek Crypto Screener
+//! application. The main function, `sort_data`, allows sorting of `CryptoData` entries based on a selected
+//! column in either ascending or descending order. The module includes specific comparison functions
+//! to handle different data types and ensure consistent ordering across various fields.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use crate::cryptodata::CryptoData;
+
+/// Sorts the `data` array of `CryptoData` items by the specified column and order.
+///
+/// This function uses a match statement to select the appropriate comparison function
+/// based on the `sort_by` field. Each comparison function handles a specific field in `CryptoData`,
+/// such as `symbol`, `name`, `price_usd`, etc. For a descending sort the comparison is
+/// reversed, so in either direction the sort is stable: rows that compare equal keep their
+/// order, and sorting again changes nothing. An unknown column leaves the data as it is.
+///
+/// Text columns compare case-insensitively. Numeric columns held as strings treat missing
+/// or unparsable values as 0.0, and computed columns sort missing values below any number.
+/// NaN counts as missing.
+///
+/// # Parameters
+///
+/// - `data`: A mutable slice of `CryptoData` items, or of shared handles to them such as
+///   `Rc<CryptoData>`, to be sorted.
+/// - `sort_by`: An optional `String` specifying the field to sort by (e.g., "symbol" or "price_usd").
+/// - `sort_asc`: A boolean that determines the sort order:
+///   - `true` for ascending order.
+///   - `false` for descending order.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::cryptodata::CryptoData;
+/// # use crypto_screener_core::sort::sort_data;
+/// let coin = |price: &str| CryptoData { price_usd: Some(price.to_string()), ..Default::default() };
+/// let mut data = vec![coin("2"), coin("1")];
+/// sort_data(&mut data, &Some("price_usd".to_string()), true);
+/// assert_eq!(data[0].price_usd.as_deref(), Some("1"));
+/// ```
+pub fn sort_data<T: Borrow<CryptoData>>(data: &mut [T], sort_by: &Option<String>, sort_asc: bool) {
+    if let Some(ref sort_by) = sort_by {
+        let compare: Option<fn(&CryptoData, &CryptoData) -> Ordering> = match sort_by.as_str() {
+            "symbol" => Some(compare_symbol),
+            "name" => Some(compare_name),
+            "price_usd" => Some(compare_price_usd),
+            "percent_change_1h" => Some(compare_percent_change_1h),
+            "percent_change_24h" => Some(compare_percent_change_24h),
+            "percent_change_7d" => Some(compare_percent_change_7d),
+            "ema_sma_spread" => Some(compare_ema_sma_spread),
+            "rsi_14" => Some(compare_rsi_14),
+            "volatility" => Some(compare_volatility),
+            "volume_zscore" => Some(compare_volume_zscore),
+            "volume24" => Some(compare_volume24),
+            "market_cap_usd" => Some(compare_market_cap_usd),
+            _ => None,
+        };
+        if let Some(compare) = compare {
+            if sort_asc {
+                data.sort_by(|a, b| compare(a.borrow(), b.borrow()));
+            } else {
+                data.sort_by(|a, b| compare(b.borrow(), a.borrow()));
+            }
+        }
+    }
+}
+
+/// Returns the direction a column is first sorted in: ascending for the alphabetical
+/// `symbol` and `name` columns, descending (largest first) for numeric columns.
+pub fn default_ascending(column: &str) -> bool {
+    column == "symbol" || column == "name"
+}
+
+/// Updates the sort state when the user picks `column`: picking the current column again
+/// reverses the direction, and a new column starts in its `default_ascending` direction.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::sort::select_sort_column;
+/// let (mut sort_by, mut sort_asc) = (Some("volume24".to_string()), false);
+/// select_sort_column(&mut sort_by, &mut sort_asc, "volume24");
+/// assert!(sort_asc);
+/// ```
+pub fn select_sort_column(sort_by: &mut Option<String>, sort_asc: &mut bool, column: &str) {
+    if sort_by.as_deref() == Some(column) {
+        *sort_asc = !*sort_asc;
+    } else {
+        *sort_by = Some(column.to_string());
+        *sort_asc = default_ascending(column);
+    }
+}
+
+/// Parses an optional `String` value as `f64` for sorting.
+///
+/// - Returns 0.0 if the value is `None`, cannot be parsed or is NaN.
+fn parse_or_zero(value: &Option<String>) -> f64 {
+    value
+        .as_ref()
+        .and_then(|s| s.parse::<f64>().ok())
+        .filter(|v| !v.is_nan())
+        .unwrap_or(0.0)
+}
+
+/// Compares two optional `String` values as `f64`, used for numeric sorting (e.g., price).
+///
+/// - Values that are `None`, cannot be parsed or are NaN compare as 0.0.
+fn compare_f64(a: &Option<String>, b: &Option<String>) -> Ordering {
+    parse_or_zero(a).partial_cmp(&parse_or_zero(b)).unwrap_or(Ordering::Equal)
+}
+
+/// Compares two optional `String` values as percentages (parsed as `f64`).
+///
+/// - Values that are `None`, cannot be parsed or are NaN compare as 0.0.
+fn compare_percent(a: &Option<String>, b: &Option<String>) -> Ordering {
+    parse_or_zero(a).partial_cmp(&parse_or_zero(b)).unwrap_or(Ordering::Equal)
+}
+
+/// Compares two optional `f64` values, used for numeric sorting (e.g., volume).
+///
+/// - `None` and NaN compare equal to each other and below any number.
+fn compare_f64_opt(a: &Option<f64>, b: &Option<f64>) -> Ordering {
+    let a = a.filter(|v| !v.is_nan());
+    let b = b.filter(|v| !v.is_nan());
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// Compares two `CryptoData` items by symbol (case-insensitive).
+///
+/// Returns an ordering based on the lexicographic order of `symbol`.
+fn compare_symbol(a: &CryptoData, b: &CryptoData) -> Ordering {
+    a.symbol.as_ref().map(|s| s
+        .to_lowercase()).unwrap_or_default()
+        .cmp(
+            &b.symbol.as_ref().map(|s| s
+                .to_lowercase()).unwrap_or_default(),
+        )
+}
+
+/// Compares two `CryptoData` items by name (case-insensitive).
+///
+/// Returns an ordering based on the lexicographic order of `name`.
+fn compare_name(a: &CryptoData, b: &CryptoData) -> Ordering {
+    a.name.as_ref().map(|s| s
+        .to_lowercase()).unwrap_or_default()
+        .cmp(
+            &b.name.as_ref().map(|s| s
+                .to_lowercase()).unwrap_or_default(),
+        )
+}
+
+/// Compares two `CryptoData` items by price in USD.
+///
+/// Uses `compare_f64` to handle the optional `price_usd` field.
+fn compare_price_usd(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64(&a.price_usd, &b.price_usd)
+}
+
+/// Compares two `CryptoData` items by 1-hour percent change.
+///
+/// Uses `compare_percent` for parsing and comparison.
+fn compare_percent_change_1h(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_percent(&a.percent_change_1h, &b.percent_change_1h)
+}
+
+/// Compares two `CryptoData` items by 24-hour percent change.
+///
+/// Uses `compare_percent` for parsing and comparison.
+fn compare_percent_change_24h(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_percent(&a.percent_change_24h, &b.percent_change_24h)
+}
+
+/// Compares two `CryptoData` items by 7-day percent change.
+///
+/// Uses `compare_percent` for parsing and comparison.
+fn compare_percent_change_7d(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_percent(&a.percent_change_7d, &b.percent_change_7d)
+}
+
+/// Compares two `CryptoData` items by EMA/SMA crossover spread.
+///
+/// Uses `compare_f64_opt` to handle the optional computed `ema_sma_spread` field.
+fn compare_ema_sma_spread(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64_opt(&a.ema_sma_spread, &b.ema_sma_spread)
+}
+
+/// Compares two `CryptoData` items by 14-period RSI.
+///
+/// Uses `compare_f64_opt` to handle the optional computed `rsi_14` field.
+fn compare_rsi_14(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64_opt(&a.rsi_14, &b.rsi_14)
+}
+
+/// Compares two `CryptoData` items by rolling volatility.
+///
+/// Uses `compare_f64_opt` to handle the optional computed `volatility` field.
+fn compare_volatility(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64_opt(&a.volatility, &b.volatility)
+}
+
+/// Compares two `CryptoData` items by volume z-score.
+///
+/// Uses `compare_f64_opt` to handle the optional computed `volume_zscore` field.
+fn compare_volume_zscore(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64_opt(&a.volume_zscore, &b.volume_zscore)
+}
+
+/// Compares two `CryptoData` items by 24-hour trading volume.
+///
+/// Uses `compare_f64_opt` to handle the optional `volume24` field.
+fn compare_volume24(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64_opt(&a.volume24, &b.volume24)
+}
+
+/// Compares two `CryptoData` items by market capitalization in USD.
+///
+/// Uses `compare_f64` to handle the optional `market_cap_usd` field.
+fn compare_market_cap_usd(a: &CryptoData, b: &CryptoData) -> Ordering {
+    compare_f64(&a.market_cap_usd, &b.market_cap_usd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use proptest::prelude::*;
+    use crate::columns::COLUMNS;
+
+    /// A record tagged with its position before sorting.
+    #[derive(Clone, Debug)]
+    struct Tagged(usize, Rc<CryptoData>);
+
+    impl Borrow<CryptoData> for Tagged {
+        fn borrow(&self) -> &CryptoData {
+            &self.1
+        }
+    }
+
+    /// The documented sort key of a value, worked out independently of the comparators.
+    #[derive(Debug, PartialEq, PartialOrd)]
+    enum Key {
+        Text(String),
+        Number(f64),
+        Computed(Option<f64>),
+    }
+
+    fn key(item: &CryptoData, column: &str) -> Key {
+        let text = |value: &Option<String>| Key::Text(value.as_deref().unwrap_or_default().to_lowercase());
+        let number = |value: &Option<String>| {
+            let parsed = value.as_deref().and_then(|s| s.parse::<f64>().ok());
+            Key::Number(parsed.filter(|v| !v.is_nan()).unwrap_or(0.0))
+        };
+        let computed = |value: Option<f64>| Key::Computed(value.filter(|v| !v.is_nan()));
+        match column {
+            "symbol" => text(&item.symbol),
+            "name" => text(&item.name),
+            "price_usd" => number(&item.price_usd),
+            "percent_change_1h" => number(&item.percent_change_1h),
+            "percent_change_24h" => number(&item.percent_change_24h),
+            "percent_change_7d" => number(&item.percent_change_7d),
+            "market_cap_usd" => number(&item.market_cap_usd),
+            "volume24" => computed(item.volume24),
+            "ema_sma_spread" => computed(item.ema_sma_spread),
+            "rsi_14" => computed(item.rsi_14),
+            "volatility" => computed(item.volatility),
+            "volume_zscore" => computed(item.volume_zscore),
+            _ => panic!("no sort key for {}", column),
+        }
+    }
+
+    /// Short text in mixed case, so that ties and case-folding both come up.
+    fn text() -> impl Strategy<Value = Option<String>> {
+        proptest::option::of("[aAbB ]{0,3}")
+    }
+
+    /// Numbers as the API sends them, with small values for ties and some that don't parse.
+    fn number() -> impl Strategy<Value = Option<String>> {
+        proptest::option::of(prop_oneof![
+            (-3i32..3).prop_map(|v| v.to_string()),
+            any::<f64>().prop_map(|v| v.to_string()),
+            Just("-0".to_string()),
+            Just("NaN".to_string()),
+            Just("inf".to_string()),
+            Just("n/a".to_string()),
+        ])
+    }
+
+    fn computed() -> impl Strategy<Value = Option<f64>> {
+        proptest::option::of(prop_oneof![
+            (-3i32..3).prop_map(f64::from),
+            any::<f64>(),
+            Just(f64::NAN),
+            Just(f64::NEG_INFINITY),
+        ])
+    }
+
+    fn crypto() -> impl Strategy<Value = CryptoData> {
+        (
+            (text(), text(), number(), number(), number(), number()),
+            (computed(), number(), computed(), computed(), computed(), computed()),
+        )
+            .prop_map(|((symbol, name, price_usd, percent_change_1h, percent_change_24h, percent_change_7d),
+                        (volume24, market_cap_usd, ema_sma_spread, rsi_14, volatility, volume_zscore))| {
+                CryptoData {
+                    symbol,
+                    name,
+                    price_usd,
+                    percent_change_1h,
+                    percent_change_24h,
+                    percent_change_7d,
+                    volume24,
+                    market_cap_usd,
+                    ema_sma_spread,
+                    rsi_14,
+                    volatility,
+                    volume_zscore,
+                    ..CryptoData::default()
+                }
+            })
+    }
+
+    fn rows() -> impl Strategy<Value = Vec<Tagged>> {
+        prop::collection::vec(crypto(), 0..24)
+            .prop_map(|items| items.into_iter().enumerate().map(|(i, item)| Tagged(i, Rc::new(item))).collect())
+    }
+
+    /// Every sortable column in both directions.
+    fn sorts() -> impl Iterator<Item = (&'static str, bool)> {
+        COLUMNS.iter().flat_map(|c| [(c.key, true), (c.key, false)])
+    }
+
+    fn sorted(rows: &[Tagged], column: &str, asc: bool) -> Vec<Tagged> {
+        let mut rows = rows.to_vec();
+        sort_data(&mut rows, &Some(column.to_string()), asc);
+        rows
+    }
+
+    fn tags(rows: &[Tagged]) -> Vec<usize> {
+        rows.iter().map(|row| row.0).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn orders_rows(rows in rows()) {
+            for (column, asc) in sorts() {
+                for pair in sorted(&rows, column, asc).windows(2) {
+                    let (a, b) = (key(&pair[0].1, column), key(&pair[1].1, column));
+                    prop_assert!(if asc { a <= b } else { a >= b }, "{} asc={}: {:?} then {:?}", column, asc, a, b);
+                }
+            }
+        }
+
+        #[test]
+        fn keeps_every_row(rows in rows()) {
+            for (column, asc) in sorts() {
+                let mut kept = tags(&sorted(&rows, column, asc));
+                kept.sort_unstable();
+                prop_assert_eq!(kept, tags(&rows), "{} asc={}", column, asc);
+            }
+        }
+
+        #[test]
+        fn keeps_ties_in_order(rows in rows()) {
+            for (column, asc) in sorts() {
+                for pair in sorted(&rows, column, asc).windows(2) {
+                    if key(&pair[0].1, column) == key(&pair[1].1, column) {
+                        prop_assert!(pair[0].0 < pair[1].0, "{} asc={}: rows {} and {}", column, asc, pair[0].0, pair[1].0);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn sorting_again_changes_nothing(rows in rows()) {
+            for (column, asc) in sorts() {
+                let once = sorted(&rows, column, asc);
+                prop_assert_eq!(tags(&sorted(&once, column, asc)), tags(&once), "{} asc={}", column, asc);
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_column_keeps_order() {
+        let rows: Vec<Tagged> = (0..3).map(|i| Tagged(i, Rc::default())).collect();
+        for asc in [true, false] {
+            assert_eq!(tags(&sorted(&rows, "rank", asc)), [0, 1, 2]);
+            assert_eq!(tags(&sorted(&rows, "", asc)), [0, 1, 2]);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Selection Statistics Module
+//!
+//! This module computes spreadsheet-style summary statistics (count, sum, mean, median,
+//! min and max) for the numeric cells a user has highlighted, grouped by column.
+
+use std::collections::HashSet;
+use crate::cryptodata::CryptoData;
+
+/// Summary statistics for a set of numeric values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStats {
+    /// Number of values.
+    pub count: usize,
+
+    /// Sum of the values.
+    pub sum: f64,
+
+    /// Arithmetic mean of the values.
+    pub mean: f64,
+
+    /// Median of the values; the mean of the two middle values for an even count.
+    pub median: f64,
+
+    /// Smallest value.
+    pub min: f64,
+
+    /// Largest value.
+    pub max: f64,
+}
+
+impl ColumnStats {
+    /// Computes statistics for `values`, returning `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crypto_screener_core::stats::ColumnStats;
+    /// let stats = ColumnStats::from_values(&[3.0, 1.0, 2.0]).unwrap();
+    /// assert_eq!(stats.median, 2.0);
+    /// ```
+    pub fn from_values(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let count = sorted.len();
+        let sum: f64 = sorted.iter().sum();
+        let median = if count % 2 == 1 {
+            sorted[count / 2]
+        } else {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+        };
+        Some(ColumnStats {
+            count,
+            sum,
+            mean: sum / count as f64,
+            median,
+            min: sorted[0],
+            max: sorted[count - 1],
+        })
+    }
+}
+
+/// Computes statistics for the selected numeric cells of `rows`, per column.
+///
+/// Columns are returned in `columns` order; columns without any selected numeric value
+/// (including text columns such as `symbol`) are omitted.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use crypto_screener_core::cryptodata::CryptoData;
+/// # use crypto_screener_core::stats::selection_stats;
+/// let btc = CryptoData { symbol: Some("BTC".to_string()), price_usd: Some("67000.5".to_string()), ..Default::default() };
+/// let selected = HashSet::from([("BTC".to_string(), "price_usd".to_string())]);
+/// let stats = selection_stats([&btc].into_iter(), &["symbol", "price_usd"], &selected);
+/// assert_eq!(stats[0].0, "price_usd");
+/// assert_eq!(stats[0].1.sum, 67000.5);
+/// ```
+pub fn selection_stats<'a, 'c>(
+    rows: impl Iterator<Item = &'a CryptoData>,
+    columns: &[&'c str],
+    selected_cells: &HashSet<(String, String)>,
+) -> Vec<(&'c str, ColumnStats)> {
+    let mut values: Vec<Vec<f64>> = vec![Vec::new(); columns.len()];
+    for item in rows {
+        let id = item.symbol.clone().unwrap_or_default();
+        for (column, column_values) in columns.iter().zip(values.iter_mut()) {
+            if selected_cells.contains(&(id.clone(), column.to_string())) {
+                column_values.extend(item.numeric_value(column));
+            }
+        }
+    }
+    columns
+        .iter()
+        .zip(values)
+        .filter_map(|(column, v)| ColumnStats::from_values(&v).map(|stats| (*column, stats)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_of_an_odd_count_is_the_middle_value() {
+        let stats = ColumnStats::from_values(&[5.0, 1.0, 3.0]).unwrap();
+        assert_eq!(stats.median, 3.0);
+    }
+
+    #[test]
+    fn median_of_an_even_count_is_the_mean_of_the_middle_values() {
+        let stats = ColumnStats::from_values(&[4.0, 1.0, 3.0, 10.0]).unwrap();
+        assert_eq!(stats.median, 3.5);
+    }
+
+    #[test]
+    fn computes_every_statistic() {
+        let stats = ColumnStats::from_values(&[2.0, -1.0, 5.0]).unwrap();
+        assert_eq!(
+            stats,
+            ColumnStats { count: 3, sum: 6.0, mean: 2.0, median: 2.0, min: -1.0, max: 5.0 }
+        );
+    }
+
+    #[test]
+    fn no_values_have_no_statistics() {
+        assert_eq!(ColumnStats::from_values(&[]), None);
+    }
+
+    #[test]
+    fn skips_text_and_unselected_cells() {
+        let coin = |symbol: &str, price: &str| CryptoData {
+            symbol: Some(symbol.to_string()),
+            price_usd: Some(price.to_string()),
+            ..CryptoData::default()
+        };
+        let rows = [coin("BTC", "3"), coin("ETH", "1"), coin("SOL", "100")];
+        let selected = HashSet::from([
+            ("BTC".to_string(), "symbol".to_string()),
+            ("BTC".to_string(), "price_usd".to_string()),
+            ("ETH".to_string(), "price_usd".to_string()),
+        ]);
+
+        let stats = selection_stats(rows.iter(), &["symbol", "price_usd"], &selected);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].0, "price_usd");
+        assert_eq!((stats[0].1.count, stats[0].1.sum), (2, 4.0));
+    }
+}
@@ -0,0 +1,171 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Treemap Layout Module
+//!
+//! This module implements the squarified treemap algorithm (Bruls, Huizing and van Wijk),
+//! which divides a rectangle into tiles whose areas are proportional to a list of weights
+//! while keeping the tiles as close to square as possible. It is pure geometry and has no
+//! knowledge of Yew or SVG.
+
+/// An axis-aligned rectangle.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub w: f64,
+    pub h: f64,
+}
+
+/// Lays out `weights` as tiles filling `bounds`.
+///
+/// Returns one rectangle per weight, in the same order as `weights`. Weights should be
+/// sorted in descending order for the best aspect ratios. Non-positive weights get an
+/// empty rectangle.
+///
+/// # Example
+///
+/// ```rust
+/// # use crypto_screener_core::treemap::{squarify, Rect};
+/// let tiles = squarify(&[6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0], Rect { x: 0.0, y: 0.0, w: 6.0, h: 4.0 });
+/// assert_eq!(tiles.len(), 7);
+/// ```
+pub fn squarify(weights: &[f64], bounds: Rect) -> Vec<Rect> {
+    let mut tiles = vec![Rect::default(); weights.len()];
+    let total: f64 = weights.iter().filter(|w| **w > 0.0).sum();
+    if total <= 0.0 {
+        return tiles;
+    }
+    let scale = bounds.w * bounds.h / total;
+    let items: Vec<(usize, f64)> = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| **w > 0.0)
+        .map(|(i, w)| (i, w * scale))
+        .collect();
+
+    let mut free = bounds;
+    let mut start = 0;
+    while start < items.len() {
+        let short = free.w.min(free.h);
+        let mut end = start + 1;
+        while end < items.len()
+            && worst(&items[start..=end], short) <= worst(&items[start..end], short)
+        {
+            end += 1;
+        }
+        free = lay_out_row(&items[start..end], free, &mut tiles);
+        start = end;
+    }
+    tiles
+}
+
+/// Returns the worst (largest) aspect ratio of a row of areas laid along a side of length `short`.
+fn worst(row: &[(usize, f64)], short: f64) -> f64 {
+    let sum: f64 = row.iter().map(|(_, a)| a).sum();
+    let max = row.iter().map(|(_, a)| *a).fold(f64::MIN, f64::max);
+    let min = row.iter().map(|(_, a)| *a).fold(f64::MAX, f64::min);
+    let side2 = short * short;
+    let sum2 = sum * sum;
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+/// Places a row of areas along the shorter side of `free`, writing their rectangles into
+/// `tiles`, and returns the space that remains.
+fn lay_out_row(row: &[(usize, f64)], free: Rect, tiles: &mut [Rect]) -> Rect {
+    let sum: f64 = row.iter().map(|(_, a)| a).sum();
+    if free.w >= free.h {
+        // Column along the left edge.
+        let width = sum / free.h;
+        let mut y = free.y;
+        for (i, area) in row {
+            let h = area / width;
+            tiles[*i] = Rect { x: free.x, y, w: width, h };
+            y += h;
+        }
+        Rect { x: free.x + width, w: free.w - width, ..free }
+    } else {
+        // Row along the top edge.
+        let height = sum / free.w;
+        let mut x = free.x;
+        for (i, area) in row {
+            let w = area / height;
+            tiles[*i] = Rect { x, y: free.y, w, h: height };
+            x += w;
+        }
+        Rect { y: free.y + height, h: free.h - height, ..free }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rect = Rect { x: 0.0, y: 0.0, w: 6.0, h: 4.0 };
+    const WEIGHTS: [f64; 7] = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn tiles_areas_are_proportional_to_weights() {
+        let tiles = squarify(&WEIGHTS, BOUNDS);
+        let total: f64 = WEIGHTS.iter().sum();
+        for (tile, weight) in tiles.iter().zip(WEIGHTS) {
+            assert!(close(tile.w * tile.h, weight / total * BOUNDS.w * BOUNDS.h));
+        }
+        let covered: f64 = tiles.iter().map(|t| t.w * t.h).sum();
+        assert!(close(covered, BOUNDS.w * BOUNDS.h));
+    }
+
+    #[test]
+    fn tiles_stay_inside_the_bounds_without_overlapping() {
+        let tiles = squarify(&WEIGHTS, BOUNDS);
+        for (i, a) in tiles.iter().enumerate() {
+            assert!(a.x >= -1e-9 && a.y >= -1e-9);
+            assert!(a.x + a.w <= BOUNDS.w + 1e-9 && a.y + a.h <= BOUNDS.h + 1e-9);
+            for b in &tiles[i + 1..] {
+                let overlap_w = (a.x + a.w).min(b.x + b.w) - a.x.max(b.x);
+                let overlap_h = (a.y + a.h).min(b.y + b.h) - a.y.max(b.y);
+                assert!(overlap_w <= 1e-9 || overlap_h <= 1e-9, "{:?} overlaps {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn non_positive_weights_get_empty_tiles() {
+        let tiles = squarify(&[2.0, 0.0, -1.0, 2.0], BOUNDS);
+        assert_eq!(tiles[1], Rect::default());
+        assert_eq!(tiles[2], Rect::default());
+        assert!(close(tiles[0].w * tiles[0].h, 12.0));
+        assert!(close(tiles[3].w * tiles[3].h, 12.0));
+    }
+
+    #[test]
+    fn nothing_to_lay_out() {
+        assert_eq!(squarify(&[0.0, 0.0], BOUNDS), vec![Rect::default(); 2]);
+        assert!(squarify(&[], BOUNDS).is_empty());
+    }
+}
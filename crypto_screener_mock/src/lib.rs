@@ -0,0 +1,253 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+
+//! # WBTek Crypto Screener Mock API
+//!
+//! A small local HTTP server that serves recorded Coinlore-format ticker responses, so
+//! the web app, the command line and the fetch layer can be tested without the live API.
+//! The fixtures live in `fixtures/` and are compiled in, and the scenario is chosen by
+//! the path:
+//!
+//! - `/api/tickers/`: the recorded tickers. `start` and `limit` query parameters page
+//!   through them as on Coinlore, which returns at most 100 coins per request.
+//! - `/empty/api/tickers/`: a well-formed response without any coins.
+//! - `/malformed/api/tickers/`: a truncated JSON body, sent with status 200.
+//! - `/rate-limited/api/tickers/`: status 429 with a `Retry-After` header, as sent when
+//!   the API is polled too often.
+//! - `/v6/latest/USD`: recorded fiat exchange rates, in the format of the default rates
+//!   endpoint.
+//!
+//! Any other path is a 404. Every response allows cross-origin requests, so the web app
+//! can be pointed at the server with `?tickers_url=`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use crypto_screener_mock::MockServer;
+//!
+//! let server = MockServer::start()?;
+//! let url = server.url("/api/tickers/?limit=5");
+//! assert!(url.starts_with("http://127.0.0.1:"));
+//! // Fetch `url` with any HTTP client, e.g. the screener's `fetch_data`.
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use serde_json::Value;
+use tiny_http::{Header, Response, Server};
+
+/// The recorded ticker response served at `/api/tickers/`.
+pub const TICKERS: &str = include_str!("../fixtures/tickers.json");
+
+/// A well-formed response without any coins.
+pub const EMPTY: &str = include_str!("../fixtures/empty.json");
+
+/// The recorded rates served at `/v6/latest/USD`, in units per US dollar.
+pub const RATES: &str = include_str!("../fixtures/rates.json");
+
+/// A ticker response cut off part way through.
+pub const MALFORMED: &str = include_str!("../fixtures/malformed.json");
+
+/// The body sent along with status 429.
+pub const RATE_LIMITED: &str = include_str!("../fixtures/rate_limited.json");
+
+/// Coins per page when no `limit` is given, as on Coinlore.
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+
+/// Seconds a rate-limited client is told to wait.
+const RETRY_AFTER_SECS: u32 = 60;
+
+/// A response chosen by `route`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reply {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Headers sent in addition to the JSON content type and CORS header.
+    pub headers: Vec<(&'static str, String)>,
+
+    /// JSON body.
+    pub body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: impl Into<String>) -> Self {
+        Self { status, headers: Vec::new(), body: body.into() }
+    }
+}
+
+/// Chooses the response for a request path, including any query string.
+pub fn route(url: &str) -> Reply {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    match path.trim_end_matches('/') {
+        "/api/tickers" => Reply::json(200, page(query)),
+        "/empty/api/tickers" => Reply::json(200, EMPTY),
+        "/malformed/api/tickers" => Reply::json(200, MALFORMED),
+        "/rate-limited/api/tickers" => Reply {
+            headers: vec![("Retry-After", RETRY_AFTER_SECS.to_string())],
+            ..Reply::json(429, RATE_LIMITED)
+        },
+        "/v6/latest/USD" => Reply::json(200, RATES),
+        _ => Reply::json(404, r#"{"error": "not found"}"#),
+    }
+}
+
+/// Returns the page of `TICKERS` selected by the `start` and `limit` query parameters.
+/// `info.coins_num` keeps the total, so a client can tell how many pages there are.
+fn page(query: &str) -> String {
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+    };
+    let start = param("start").unwrap_or(0);
+    let limit = param("limit").unwrap_or(DEFAULT_PAGE_LIMIT);
+
+    let mut payload: Value = serde_json::from_str(TICKERS).expect("tickers fixture is valid JSON");
+    if let Some(data) = payload.get_mut("data").and_then(Value::as_array_mut) {
+        let page: Vec<Value> = data.iter().skip(start).take(limit).cloned().collect();
+        *data = page;
+    }
+    payload.to_string()
+}
+
+/// A running mock server, stopped when dropped.
+pub struct MockServer {
+    server: Arc<Server>,
+    thread: Option<JoinHandle<()>>,
+    base_url: String,
+}
+
+impl MockServer {
+    /// Starts a server on a free local port, as tests do.
+    pub fn start() -> io::Result<Self> {
+        Self::bind("127.0.0.1:0")
+    }
+
+    /// Starts a server on `addr`, e.g. `"127.0.0.1:8080"`.
+    pub fn bind(addr: &str) -> io::Result<Self> {
+        let server = Arc::new(Server::http(addr).map_err(io::Error::other)?);
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| io::Error::other("mock server is not listening on TCP"))?;
+        let handle = Arc::clone(&server);
+        let thread = thread::spawn(move || serve(&handle));
+        Ok(Self { server, thread: Some(thread), base_url: format!("http://{}", addr) })
+    }
+
+    /// Returns the server's address, e.g. `http://127.0.0.1:8080`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the full URL of `path`, e.g. `url("/api/tickers/")`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Serves requests until the process is stopped.
+    pub fn join(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Answers requests until the server is unblocked.
+fn serve(server: &Server) {
+    for request in server.incoming_requests() {
+        let reply = route(request.url());
+        let mut response = Response::from_string(reply.body)
+            .with_status_code(reply.status)
+            .with_header(header("Content-Type", "application/json"))
+            .with_header(header("Access-Control-Allow-Origin", "*"));
+        for (name, value) in &reply.headers {
+            response.add_header(header(name, value));
+        }
+        let _ = request.respond(response);
+    }
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(body: &str) -> Vec<String> {
+        let payload: Value = serde_json::from_str(body).unwrap();
+        payload["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|coin| coin["symbol"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn pages_through_the_tickers() {
+        let all = symbols(&route("/api/tickers/").body);
+        assert_eq!(all.len(), 10);
+        assert_eq!(symbols(&route("/api/tickers/?start=2&limit=3").body), all[2..5]);
+        assert!(symbols(&route("/api/tickers/?start=50").body).is_empty());
+
+        let payload: Value = serde_json::from_str(&route("/api/tickers/?limit=1").body).unwrap();
+        assert_eq!(payload["info"]["coins_num"], 10);
+    }
+
+    #[test]
+    fn serves_failure_scenarios() {
+        assert!(symbols(&route("/empty/api/tickers/").body).is_empty());
+        assert!(serde_json::from_str::<Value>(&route("/malformed/api/tickers/").body).is_err());
+
+        let limited = route("/rate-limited/api/tickers/");
+        assert_eq!(limited.status, 429);
+        assert_eq!(limited.headers, vec![("Retry-After", "60".to_string())]);
+
+        assert_eq!(route("/api/other/").status, 404);
+    }
+
+    #[test]
+    fn serves_rates() {
+        let payload: Value = serde_json::from_str(&route("/v6/latest/USD").body).unwrap();
+        assert_eq!(payload["rates"]["EUR"], 0.92);
+    }
+}
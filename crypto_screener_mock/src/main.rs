@@ -0,0 +1,81 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+
+//! # WBTek Crypto Screener Mock API Server
+//!
+//! Serves the recorded ticker fixtures on a fixed port, for trying the web app or the
+//! command line against malformed, empty, paginated and rate-limited responses.
+//!
+//! ## Example
+//!
+//! ```text
+//! crypto-screener-mock --port 8080
+//! crypto-screener --url http://127.0.0.1:8080/rate-limited/api/tickers/
+//! ```
+//!
+//! In the browser, add `?tickers_url=http://127.0.0.1:8080/api/tickers/` to the page URL.
+
+use std::process::ExitCode;
+use clap::Parser;
+use crypto_screener_mock::MockServer;
+
+/// Scenario paths listed at startup.
+const ROUTES: [&str; 5] = [
+    "/api/tickers/?start=0&limit=100",
+    "/empty/api/tickers/",
+    "/malformed/api/tickers/",
+    "/rate-limited/api/tickers/",
+    "/v6/latest/USD",
+];
+
+/// Command-line options.
+#[derive(Parser, Debug)]
+#[command(name = "crypto-screener-mock", version, about = "Serves recorded Coinlore ticker responses locally.")]
+struct Args {
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Port to listen on.
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match MockServer::bind(&format!("{}:{}", args.host, args.port)) {
+        Ok(server) => {
+            for route in ROUTES {
+                println!("{}", server.url(route));
+            }
+            server.join();
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("crypto-screener-mock: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -27,19 +27,24 @@
 //! This module serves as the main entry point for the Yew application, 
 //! initializing the logger and rendering the main application model. 
 //!
-//! To start the application, use `run_app()` as defined below.
+//! To start the application, use `run_app()` as defined below. Host pages can instead
+//! mount screeners into their own elements with the JavaScript API in `modules::api`,
+//! whose `mount` and `Screener` are re-exported here.
 
 use yew::Renderer;
-use wasm_bindgen::prelude::wasm_bindgen;
 
 mod modules;
 use modules::component::Model;
 
+pub use modules::api::{mount, Screener};
+
 /// Entry point for the Yew application.
 ///
 /// This function is called when the WebAssembly module is initialized
 /// and starts the main Yew application. It also sets up a logger with
-/// an `Info` level for debugging purposes.
+/// an `Info` level for debugging purposes. Nothing is rendered if the
+/// page has `<html data-screener-mount="manual">`, which leaves mounting
+/// to the host page through `mount`.
 ///
 /// # Examples
 ///
@@ -67,11 +72,18 @@ use modules::component::Model;
 /// # See Also
 /// * [`Renderer`](https://docs.rs/yew/latest/yew/struct.Renderer.html) - Renderer struct in Yew
 /// * [`console_log`](https://docs.rs/console_log/latest/console_log/index.html) - Logging setup
-#[wasm_bindgen(start)]
+///
+/// It is not a start function in test builds, so that browser tests can mount their own
+/// `Model`.
+#[cfg_attr(not(test), wasm_bindgen::prelude::wasm_bindgen(start))]
 pub fn run_app() {
     if console_log::init_with_level(log::Level::Info).is_err() {
         log::warn!("Logger already initialized.");
     }
+    if modules::api::manual_mount() {
+        log::info!("Waiting for the host page to mount screeners.");
+        return;
+    }
     log::info!("Starting the Yew application...");
     Renderer::<Model>::new().render();
 }
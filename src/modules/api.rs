@@ -0,0 +1,427 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+
+//! # JavaScript API Module
+//!
+//! This module exports a JavaScript API through wasm-bindgen, so a host page such as the
+//! WBTek.net WordPress demo can mount screeners where it wants them and work with them:
+//!
+//! ```js
+//! import init, { mount } from './pkg/crypto_screener.js';
+//!
+//! await init();
+//! const screener = mount('screener', { sortBy: 'percent_change_24h', compact: true });
+//! screener.setFilter('volume24>1000000');
+//! screener.on('selection', cells => console.log(cells));
+//! screener.on('data', ({ rows }) => console.log(rows, JSON.parse(screener.getData())));
+//! ```
+//!
+//! The stand-alone app still mounts itself into `<body>` when the module loads; a host
+//! page that mounts its own screeners turns that off with
+//! `<html data-screener-mount="manual">`.
+//!
+//! ## Key Items
+//!
+//! - `mount`: Renders a screener into an element, configured by a JSON object.
+//! - `Screener`: The handle `mount` returns, for sorting, filtering, events and data.
+//! - `manual_mount`: Whether the host page has turned off mounting into `<body>`.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use js_sys::Function;
+use serde::{Deserialize, Deserializer, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+use yew::{AppHandle, Callback, Renderer};
+use super::columns::{column, COLUMNS};
+use super::component::{Model, ScreenerProps};
+use super::export::ExportFormat;
+use super::message::Msg;
+use super::sort::default_ascending;
+use super::storage::Store;
+
+/// Attribute of the `<html>` element that stops the app mounting into `<body>` when set
+/// to `manual`.
+const MOUNT_ATTRIBUTE: &str = "data-screener-mount";
+
+/// Configuration accepted by `mount`: a JavaScript object, or JSON text, whose camelCase
+/// keys mirror `ScreenerProps`. Every key is optional, and unknown keys are rejected so
+/// that typos don't go unnoticed.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+struct Config {
+    /// `None` when left out, for the default sort, and `Some(None)` when `null`, for none.
+    #[serde(deserialize_with = "nullable")]
+    sort_by: Option<Option<String>>,
+    sort_asc: Option<bool>,
+    columns: Option<Vec<String>>,
+    filter: String,
+    provider: Option<String>,
+    refresh_secs: Option<u32>,
+    compact: Option<bool>,
+    sync_url: Option<bool>,
+    storage_key: Option<String>,
+}
+
+/// Events a host can subscribe to with `Screener.on`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EventKind {
+    /// The highlighted cells changed; listeners get an array of `{ symbol, column }`.
+    Selection,
+
+    /// Data was loaded; listeners get `{ rows, updated }`, with the number of coins and
+    /// the time in milliseconds since the epoch.
+    Data,
+}
+
+impl EventKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "selection" => Some(EventKind::Selection),
+            "data" => Some(EventKind::Data),
+            _ => None,
+        }
+    }
+}
+
+/// A highlighted cell, as passed to `selection` listeners.
+#[derive(Serialize)]
+struct SelectedCell {
+    symbol: String,
+    column: String,
+}
+
+/// A data update, as passed to `data` listeners.
+#[derive(Serialize)]
+struct DataUpdate {
+    rows: usize,
+    updated: f64,
+}
+
+/// The JavaScript functions subscribed to a screener's events.
+#[derive(Default)]
+struct Listeners {
+    next_id: Cell<u32>,
+    callbacks: RefCell<Vec<(u32, EventKind, Function)>>,
+}
+
+impl Listeners {
+    fn add(&self, kind: EventKind, callback: Function) -> u32 {
+        let id = self.next_id.get() + 1;
+        self.next_id.set(id);
+        self.callbacks.borrow_mut().push((id, kind, callback));
+        id
+    }
+
+    fn remove(&self, id: u32) {
+        self.callbacks.borrow_mut().retain(|(other, _, _)| *other != id);
+    }
+
+    /// Calls every listener for `kind` with `payload`. The list is copied first, so a
+    /// listener may subscribe or unsubscribe while being called.
+    fn emit(&self, kind: EventKind, payload: &impl Serialize) {
+        let callbacks: Vec<Function> = self
+            .callbacks
+            .borrow()
+            .iter()
+            .filter(|(_, other, _)| *other == kind)
+            .map(|(_, _, callback)| callback.clone())
+            .collect();
+        if callbacks.is_empty() {
+            return;
+        }
+        let Ok(payload) = serde_wasm_bindgen::to_value(payload) else {
+            return;
+        };
+        for callback in callbacks {
+            if let Err(err) = callback.call1(&JsValue::NULL, &payload) {
+                log::warn!("Screener {:?} listener failed: {:?}", kind, err);
+            }
+        }
+    }
+}
+
+/// A mounted screener, returned by `mount`.
+#[wasm_bindgen]
+pub struct Screener {
+    app: Option<AppHandle<Model>>,
+    listeners: Rc<Listeners>,
+}
+
+#[wasm_bindgen]
+impl Screener {
+    /// Sorts by `column`, ascending if `ascending` is true. Without `ascending`, the
+    /// column's usual direction is used: ascending for text, descending for numbers.
+    /// A `null` column clears the sort, keeping the current order until the next refresh
+    /// brings the API's order.
+    ///
+    /// Throws if the column is unknown or the screener has been unmounted.
+    #[wasm_bindgen(js_name = setSort)]
+    pub fn set_sort(&self, column: Option<String>, ascending: Option<bool>) -> Result<(), JsValue> {
+        if let Some(column) = &column {
+            check_column(column)?;
+        }
+        let ascending = ascending.unwrap_or_else(|| column.as_deref().is_some_and(default_ascending));
+        self.app()?.send_message(Msg::SetSort(column, ascending));
+        Ok(())
+    }
+
+    /// Replaces the filter query, as if typed into the filter box.
+    ///
+    /// Throws if the screener has been unmounted.
+    #[wasm_bindgen(js_name = setFilter)]
+    pub fn set_filter(&self, query: &str) -> Result<(), JsValue> {
+        self.app()?.send_message(Msg::SetFilter(query.to_string()));
+        Ok(())
+    }
+
+    /// Calls `callback` on every `selection` or `data` event, and returns an id for `off`.
+    ///
+    /// Throws if the event name is unknown.
+    pub fn on(&self, event: &str, callback: Function) -> Result<u32, JsValue> {
+        let kind = EventKind::from_name(event)
+            .ok_or_else(|| error(&format!("unknown screener event \"{}\"", event)))?;
+        Ok(self.listeners.add(kind, callback))
+    }
+
+    /// Unsubscribes the listener with the id returned by `on`.
+    pub fn off(&self, id: u32) {
+        self.listeners.remove(id);
+    }
+
+    /// Returns every loaded coin as JSON text: an array of objects with the raw value of
+    /// every column, in the current sort order, as in the JSON export.
+    ///
+    /// Throws if the screener has been unmounted or has not rendered yet.
+    #[wasm_bindgen(js_name = getData)]
+    pub fn get_data(&self) -> Result<String, JsValue> {
+        let app = self.app()?;
+        let model = app.get_component().ok_or_else(|| error("the screener has not rendered yet"))?;
+        let keys: Vec<&str> = COLUMNS.iter().map(|c| c.key).collect();
+        Ok(ExportFormat::Json.render(model.data.iter().map(|row| row.as_ref()), &keys))
+    }
+
+    /// Removes the screener from the page and stops its refreshes.
+    pub fn unmount(&mut self) {
+        if let Some(app) = self.app.take() {
+            app.destroy();
+        }
+        self.listeners.callbacks.borrow_mut().clear();
+    }
+}
+
+impl Screener {
+    fn app(&self) -> Result<&AppHandle<Model>, JsValue> {
+        self.app.as_ref().ok_or_else(|| error("the screener has been unmounted"))
+    }
+}
+
+/// Renders a screener into the element with id `element_id`, configured by `config`: an
+/// object or JSON text such as `{"sortBy": "price_usd", "columns": ["symbol", "price_usd"]}`.
+/// `config` may be left out for the default screener.
+///
+/// A mounted screener saves the user's settings only if `storageKey` names where, so
+/// screeners sharing a page keep their own settings; without it, nothing is saved.
+///
+/// Throws if there is no such element or the configuration is invalid.
+#[wasm_bindgen]
+pub fn mount(element_id: &str, config: JsValue) -> Result<Screener, JsValue> {
+    let config = parse_config(config)?;
+    if let Some(Some(sort_by)) = &config.sort_by {
+        check_column(sort_by)?;
+    }
+    if let Some(columns) = &config.columns {
+        if columns.is_empty() {
+            return Err(error("columns must not be empty"));
+        }
+        columns.iter().try_for_each(|key| check_column(key))?;
+    }
+    let root = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(element_id))
+        .ok_or_else(|| error(&format!("no element with id \"{}\"", element_id)))?;
+    let listeners = Rc::new(Listeners::default());
+    let app = Renderer::<Model>::with_root_and_props(root, props(config, &listeners)).render();
+    Ok(Screener { app: Some(app), listeners })
+}
+
+/// Returns `true` if the host page mounts its screeners itself, with
+/// `<html data-screener-mount="manual">`.
+pub fn manual_mount() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+        .and_then(|html| html.get_attribute(MOUNT_ATTRIBUTE))
+        .is_some_and(|mode| mode.trim() == "manual")
+}
+
+/// Reads the configuration from a JavaScript object or JSON text.
+fn parse_config(config: JsValue) -> Result<Config, JsValue> {
+    if config.is_undefined() || config.is_null() {
+        return Ok(Config::default());
+    }
+    let parsed = match config.as_string() {
+        Some(text) => serde_json::from_str(&text).map_err(|err| err.to_string()),
+        None => serde_wasm_bindgen::from_value(config).map_err(|err| err.to_string()),
+    };
+    parsed.map_err(|err| error(&format!("invalid screener config: {}", err)))
+}
+
+/// Builds the screener's properties, with event callbacks that notify `listeners`.
+fn props(config: Config, listeners: &Rc<Listeners>) -> ScreenerProps {
+    let mut props = ScreenerProps::default();
+    if let Some(sort_by) = config.sort_by {
+        props.sort_by = sort_by;
+    }
+    props.sort_asc = config.sort_asc;
+    props.columns = config.columns;
+    props.filter = config.filter;
+    props.provider = config.provider;
+    if let Some(refresh_secs) = config.refresh_secs {
+        props.refresh_secs = refresh_secs;
+    }
+    props.compact = config.compact;
+    if let Some(sync_url) = config.sync_url {
+        props.sync_url = sync_url;
+    }
+    // Mounted screeners only save settings under a key of their own.
+    props.store = config.storage_key.map_or(Store::Off, Store::Named);
+
+    let selection = Rc::clone(listeners);
+    props.onselectionchange = Callback::from(move |cells: Vec<(String, String)>| {
+        let cells: Vec<SelectedCell> = cells
+            .into_iter()
+            .map(|(symbol, column)| SelectedCell { symbol, column })
+            .collect();
+        selection.emit(EventKind::Selection, &cells);
+    });
+    let data = Rc::clone(listeners);
+    props.ondataupdate = Callback::from(move |rows: usize| {
+        data.emit(EventKind::Data, &DataUpdate { rows, updated: js_sys::Date::now() });
+    });
+    props
+}
+
+/// Deserializes a field that may be `null`, keeping `null` apart from a missing field.
+fn nullable<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Option<String>>, D::Error> {
+    Option::deserialize(deserializer).map(Some)
+}
+
+/// Returns an error for an unknown column key.
+fn check_column(key: &str) -> Result<(), JsValue> {
+    match column(key) {
+        Some(_) => Ok(()),
+        None => Err(error(&format!("unknown column \"{}\"", key))),
+    }
+}
+
+/// Wraps a message in a JavaScript `Error`.
+fn error(message: &str) -> JsValue {
+    js_sys::Error::new(message).into()
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use gloo_timers::future::TimeoutFuture;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    const TICKERS: &str = include_str!("../../crypto_screener_mock/fixtures/tickers.json");
+
+    fn symbols(screener: &Screener) -> Vec<String> {
+        let data: serde_json::Value = serde_json::from_str(&screener.get_data().unwrap()).unwrap();
+        data.as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row["symbol"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    async fn mounts_and_drives_a_screener() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.create_element("div").unwrap();
+        root.set_id("api-screener");
+        document.body().unwrap().append_child(&root).unwrap();
+
+        let provider = format!("data:application/json,{}", String::from(js_sys::encode_uri_component(TICKERS)));
+        let config = serde_json::json!({ "provider": provider, "compact": false, "syncUrl": false });
+        let mut screener = mount("api-screener", JsValue::from_str(&config.to_string())).unwrap();
+
+        let updates = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&updates);
+        let listener = Closure::<dyn Fn(JsValue)>::new(move |_| counter.set(counter.get() + 1));
+        screener.on("data", listener.as_ref().unchecked_ref::<Function>().clone()).unwrap();
+        for _ in 0..100 {
+            if updates.get() > 0 {
+                break;
+            }
+            TimeoutFuture::new(20).await;
+        }
+        assert_eq!(updates.get(), 1);
+        assert_eq!(symbols(&screener)[0], "USDT");
+
+        screener.set_sort(Some("price_usd".to_string()), Some(true)).unwrap();
+        TimeoutFuture::new(20).await;
+        assert_eq!(symbols(&screener)[0], "TRX");
+        screener.set_sort(None, None).unwrap();
+        TimeoutFuture::new(20).await;
+        let model = screener.app().unwrap().get_component().unwrap();
+        assert_eq!(model.sort_by, None);
+        drop(model);
+
+        assert!(screener.set_sort(Some("rank".to_string()), None).is_err());
+        assert!(screener.on("click", Function::new_no_args("")).is_err());
+        screener.unmount();
+        assert!(screener.set_filter("btc").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn rejects_bad_configs() {
+        assert!(mount("no-such-element", JsValue::UNDEFINED).is_err());
+        assert!(parse_config(JsValue::from_str(r#"{"sortby": "price_usd"}"#)).is_err());
+        assert!(parse_config(JsValue::from_str("{")).is_err());
+        assert_eq!(parse_config(JsValue::NULL).unwrap().filter, "");
+        let message = |config: &str| match mount("no-such-element", JsValue::from_str(config)) {
+            Err(err) => String::from(js_sys::Error::from(err).message()),
+            Ok(_) => panic!("expected {} to be rejected", config),
+        };
+        assert_eq!(message(r#"{"columns": ["symbol", "rank"]}"#), "unknown column \"rank\"");
+        assert_eq!(message(r#"{"columns": []}"#), "columns must not be empty");
+    }
+
+    #[wasm_bindgen_test]
+    fn null_sort_clears_the_default_sort() {
+        let listeners = Rc::new(Listeners::default());
+        let cleared = parse_config(JsValue::from_str(r#"{"sortBy": null}"#)).unwrap();
+        assert_eq!(props(cleared, &listeners).sort_by, None);
+        let default = parse_config(JsValue::from_str("{}")).unwrap();
+        assert_eq!(props(default, &listeners).sort_by, Some("volume24".to_string()));
+    }
+}
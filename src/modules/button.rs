@@ -32,11 +32,16 @@
 //!
 //! - `SortOrder`: An enum representing the current sort state of the button (None, Ascending, or Descending).
 //! - `HeaderProps`: A struct that holds properties for the `HeaderButton` component, including the 
-//!   label text, sort order, and a callback function for handling click events.
-//! - `header_button`: A function component in Yew that renders a `<th>` element styled according to the
-//!   current sort state, and invokes a callback when clicked.
+//!   label text, sort order, column key, and callback functions for clicks, reordering and resizing.
+//! - `header_button`: A function component in Yew that renders a draggable, resizable `<th>` element
+//!   styled according to the current sort state, containing a `<button>` that invokes a callback
+//!   when clicked or activated from the keyboard.
 
-use yew::prelude::{Properties, Callback, MouseEvent, function_component, Html, html};
+use web_sys::{Element, HtmlElement};
+use yew::prelude::{
+    Properties, Callback, DragEvent, MouseEvent, PointerEvent, TargetCast,
+    function_component, use_mut_ref, use_node_ref, Html, html,
+};
 
 /// Represents the sorting state of a header button.
 ///
@@ -79,17 +84,42 @@ impl SortOrder {
             SortOrder::Descending => "descending",
         }
     }
+
+    /// Returns the value of the header's `aria-sort` attribute, which tells assistive
+    /// technology how the column is sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(SortOrder::None.aria_sort(), "none");
+    /// assert_eq!(SortOrder::Descending.aria_sort(), "descending");
+    /// ```
+    pub fn aria_sort(&self) -> &'static str {
+        match self {
+            SortOrder::None => "none",
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+        }
+    }
 }
 
+/// MIME type under which a header's column key is carried during drag-and-drop.
+const DRAG_FORMAT: &str = "text/plain";
+
 /// Properties for the `HeaderButton` component.
 ///
-/// `HeaderProps` defines the label text, the current sort order, and a
-/// callback function to handle click events. These properties are passed 
-/// to `HeaderButton` to configure its appearance and behavior.
+/// `HeaderProps` defines the label text, the current sort order, and
+/// callback functions to handle clicks, column reordering and resizing.
+/// These properties are passed to `HeaderButton` to configure its
+/// appearance and behavior.
 ///
 /// - `label`: The text to display on the button.
 /// - `sort_order`: The current sort order for this header button (None, Ascending, or Descending).
 /// - `onclick`: A callback triggered when the button is clicked.
+/// - `column`: The key of the column this header belongs to.
+/// - `ondropcolumn`: A callback triggered with the key of another header dropped onto this one.
+/// - `onresize`: A callback triggered with the new width while the resize handle is dragged.
+/// - `onresizeend`: A callback triggered once when the resize handle is released.
 #[derive(Properties, Clone, PartialEq)]
 pub struct HeaderProps {
     /// Text label displayed on the header button.
@@ -100,6 +130,18 @@ pub struct HeaderProps {
 
     /// Callback function triggered on click events.
     pub onclick: Callback<MouseEvent>,
+
+    /// Key of the column, carried as drag-and-drop data when the header is dragged.
+    pub column: String,
+
+    /// Callback triggered with the key of the column whose header was dropped here.
+    pub ondropcolumn: Callback<String>,
+
+    /// Callback triggered with the new column width, in pixels, during a resize.
+    pub onresize: Callback<u32>,
+
+    /// Callback triggered when a resize finishes, so the final width can be saved.
+    pub onresizeend: Callback<()>,
 }
 
 /// A Yew function component that renders a table header button for sorting.
@@ -109,31 +151,113 @@ pub struct HeaderProps {
 /// to update the sorting state. The button applies different CSS classes to reflect
 /// the sort order, which can be styled accordingly.
 ///
+/// The header can also be dragged onto another header to reorder columns, and has a
+/// resize handle on its right edge. The handle captures the pointer while dragged, so
+/// resizing continues smoothly even when the pointer leaves the header.
+///
 /// # Parameters
 ///
-/// - `props`: [`HeaderProps`] containing the label, sort order, and a click event callback.
+/// - `props`: [`HeaderProps`] containing the label, sort order, column key and callbacks.
 ///
 /// # HTML Structure
 ///
-/// The rendered HTML includes a `<th>` element with dynamic class names based on `sort_order`.
-/// When clicked, the `onclick` callback is triggered.
+/// The rendered HTML includes a draggable `<th>` element with dynamic class names and an
+/// `aria-sort` attribute based on `sort_order`. It contains a `<button>` showing the label,
+/// which triggers the `onclick` callback when clicked or activated with Enter or Space, and
+/// a `<span class="resize-handle">`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// let props = HeaderProps {
-///     label: "Name".to_string(),
-///     sort_order: SortOrder::Ascending,
-///     onclick: Callback::noop(),
-/// };
-/// let header_button = header_button(&props);
+/// html! {
+///     <HeaderButton
+///         label={"Name".to_string()}
+///         sort_order={SortOrder::Ascending}
+///         onclick={Callback::noop()}
+///         column={"name".to_string()}
+///         ondropcolumn={Callback::noop()}
+///         onresize={Callback::noop()}
+///         onresizeend={Callback::noop()}
+///     />
+/// }
 /// ```
 #[function_component(HeaderButton)]
 pub fn header_button(props: &HeaderProps) -> Html {
-    let HeaderProps { label, sort_order, onclick } = props.clone();
+    let HeaderProps { label, sort_order, onclick, column, ondropcolumn, onresize, onresizeend } = props.clone();
     let class = format!("header-button {}", sort_order.class());
+    let th_ref = use_node_ref();
+    // Pointer x position and header width when a resize started; `None` when not resizing.
+    let resize_start = use_mut_ref(|| None::<(i32, i32)>);
+
+    let ondragstart = {
+        let resize_start = resize_start.clone();
+        Callback::from(move |e: DragEvent| {
+            if resize_start.borrow().is_some() {
+                e.prevent_default();
+                return;
+            }
+            if let Some(data) = e.data_transfer() {
+                let _ = data.set_data(DRAG_FORMAT, &column);
+                data.set_effect_allowed("move");
+            }
+        })
+    };
+    let ondragover = Callback::from(|e: DragEvent| e.prevent_default());
+    let ondrop = Callback::from(move |e: DragEvent| {
+        e.prevent_default();
+        let dragged = e.data_transfer().and_then(|data| data.get_data(DRAG_FORMAT).ok());
+        if let Some(dragged) = dragged.filter(|key| !key.is_empty()) {
+            ondropcolumn.emit(dragged);
+        }
+    });
+
+    let onpointerdown = {
+        let th_ref = th_ref.clone();
+        let resize_start = resize_start.clone();
+        Callback::from(move |e: PointerEvent| {
+            e.stop_propagation();
+            let width = th_ref.cast::<HtmlElement>().map(|th| th.offset_width()).unwrap_or_default();
+            *resize_start.borrow_mut() = Some((e.client_x(), width));
+            if let Some(handle) = e.target_dyn_into::<Element>() {
+                let _ = handle.set_pointer_capture(e.pointer_id());
+            }
+        })
+    };
+    let onpointermove = {
+        let resize_start = resize_start.clone();
+        Callback::from(move |e: PointerEvent| {
+            if let Some((start_x, start_width)) = *resize_start.borrow() {
+                onresize.emit((start_width + e.client_x() - start_x).max(0) as u32);
+            }
+        })
+    };
+    let onpointerup = Callback::from(move |_: PointerEvent| {
+        if resize_start.borrow_mut().take().is_some() {
+            onresizeend.emit(());
+        }
+    });
+    let stop_click = Callback::from(|e: MouseEvent| e.stop_propagation());
 
     html! {
-        <th class={class} onclick={onclick}>{ label }</th>
+        <th
+            ref={th_ref}
+            class={class}
+            aria-sort={sort_order.aria_sort()}
+            draggable="true"
+            ondragstart={ondragstart}
+            ondragover={ondragover}
+            ondrop={ondrop}
+        >
+            <button type="button" class="header-label" onclick={onclick}>{ label }</button>
+            <span
+                class="resize-handle"
+                aria-hidden="true"
+                onpointerdown={onpointerdown}
+                onpointermove={onpointermove}
+                onpointerup={onpointerup.clone()}
+                onpointercancel={onpointerup}
+                onclick={stop_click}
+            />
+        </th>
     }
-}
\ No newline at end of file
+}
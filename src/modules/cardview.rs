@@ -0,0 +1,128 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Card View Module
+//!
+//! This module provides the `view_cards` function, which renders the filtered coins as a
+//! list of compact cards for narrow screens. Each card shows the coin's symbol and name
+//! followed by its other visible columns, with the same formatting rules and highlighting
+//! as the table. Sorting is offered through a dropdown, since there are no header buttons.
+
+use web_sys::HtmlSelectElement;
+use yew::prelude::{Context, Event, Html, TargetCast, html};
+use super::component::Model;
+use super::history;
+use super::i18n::tr;
+use super::message::Msg;
+
+/// Renders the sort controls and one card per visible row.
+///
+/// # Card Interactivity
+///
+/// Tapping a field sends `Msg::ToggleCellSelection` for that coin and column, just like
+/// clicking a table cell, so highlighting, statistics and copying work the same way.
+/// When paging is enabled only the current page's cards are rendered.
+///
+/// While nothing is sorted the dropdown shows an empty choice and the direction button is
+/// disabled, since there is no sort to reverse.
+///
+/// # Example
+///
+/// ```rust
+/// let cards = view_cards(ctx, model);
+/// ```
+pub fn view_cards(ctx: &Context<Model>, model: &Model) -> Html {
+    let link = ctx.link();
+    let rows = model.page_rows();
+    let quote = model.quote();
+    let columns = model.columns.visible();
+    let fields: Vec<_> = columns
+        .iter()
+        .filter(|column| column.key != "symbol" && column.key != "name")
+        .collect();
+    let sort_by = model.sort_by.clone();
+    let lang = model.language;
+    let direction = tr(lang, if model.sort_asc { "cards.ascending" } else { "cards.descending" });
+
+    html! {
+        <>
+            <div class="card-sort">
+                <label>
+                    { tr(lang, "cards.sort") }
+                    { " " }
+                    <select onchange={link.callback(|e: Event| {
+                        Msg::SortBy(e.target_unchecked_into::<HtmlSelectElement>().value())
+                    })}>
+                        { if sort_by.is_none() {
+                            html! { <option value="" selected=true disabled=true hidden=true /> }
+                        } else {
+                            html! {}
+                        }}
+                        { for columns.iter().map(|column| html! {
+                            <option value={column.key} selected={sort_by.as_deref() == Some(column.key)}>
+                                { column.title(&quote, lang) }
+                            </option>
+                        }) }
+                    </select>
+                </label>
+                <button
+                    disabled={sort_by.is_none()}
+                    onclick={link.batch_callback(move |_| sort_by.clone().map(Msg::SortBy))}
+                >
+                    { direction }
+                </button>
+            </div>
+            <div class="cards">
+                { for rows.iter().map(|item| {
+                    let id = history::series_key(item).unwrap_or_default().to_string();
+                    let class = |key: &str| {
+                        (model.cursor.as_ref() == Some(&(id.clone(), key.to_string()))).then_some("cursor")
+                    };
+                    let toggle = |key: &'static str| {
+                        let id = id.clone();
+                        link.callback(move |_| Msg::ToggleCellSelection(id.clone(), key.to_string()))
+                    };
+                    html! {
+                        <div class="card">
+                            <div class="card-title">
+                                <b class={class("symbol")} style={model.cell_style(item, "symbol")} onclick={toggle("symbol")}>{ item.symbol.as_deref().unwrap_or_default() }</b>
+                                <span class={class("name")} style={model.cell_style(item, "name")} onclick={toggle("name")}>
+                                    { item.truncated_name() }
+                                </span>
+                            </div>
+                            <dl>
+                                { for fields.iter().map(|column| html! {
+                                    <div class={class(column.key)} style={model.cell_style(item, column.key)} onclick={toggle(column.key)}>
+                                        <dt>{ column.title(&quote, lang) }</dt>
+                                        <dd>{ (column.value)(item, &quote, &model.number_locale) }</dd>
+                                    </div>
+                                }) }
+                            </dl>
+                        </div>
+                    }
+                }) }
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,66 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Column Chooser View Module
+//!
+//! This module provides the `view_column_chooser` function, which renders a panel of
+//! checkboxes for showing and hiding table columns, plus a button to restore the default
+//! layout. Column order and width are changed directly on the table header.
+
+use yew::prelude::{Context, Html, html};
+use super::component::Model;
+use super::i18n::tr;
+use super::message::Msg;
+
+/// Renders the column chooser panel.
+///
+/// Columns are listed in their current display order. Unchecking a box sends
+/// `Msg::ToggleColumn`; the last visible column cannot be hidden.
+///
+/// # Example
+///
+/// ```rust
+/// let chooser = view_column_chooser(ctx, model);
+/// ```
+pub fn view_column_chooser(ctx: &Context<Model>, model: &Model) -> Html {
+    let link = ctx.link();
+    html! {
+        <div class="column-chooser">
+            { for model.columns.ordered().into_iter().map(|column| {
+                let key = column.key;
+                html! {
+                    <label>
+                        <input
+                            type="checkbox"
+                            checked={model.columns.is_visible(key)}
+                            onchange={link.callback(move |_| Msg::ToggleColumn(key.to_string()))}
+                        />
+                        { column.localized(model.language) }
+                    </label>
+                }
+            }) }
+            <button onclick={link.callback(|_| Msg::ResetColumns)}>{ tr(model.language, "chooser.reset") }</button>
+        </div>
+    }
+}
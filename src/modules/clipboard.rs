@@ -0,0 +1,90 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Clipboard Module
+//!
+//! This module writes text to the system clipboard through the asynchronous
+//! `navigator.clipboard.writeText` browser API, and listens for the Ctrl/Cmd+C shortcut
+//! so highlighted cells can be copied without interfering with ordinary text copying.
+
+use gloo_events::EventListener;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlInputElement, KeyboardEvent};
+use yew::html::Scope;
+use yew::NodeRef;
+use super::component::Model;
+use super::keyboard::is_for_screener;
+use super::message::Msg;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Binding for `navigator.clipboard.writeText`, which returns a `Promise`.
+    #[wasm_bindgen(js_namespace = ["navigator", "clipboard"], js_name = writeText, catch)]
+    fn write_text(text: &str) -> Result<js_sys::Promise, JsValue>;
+}
+
+/// Copies `text` to the system clipboard.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the Clipboard API is unavailable (e.g. on a page
+/// not served over HTTPS) or the browser denies the write.
+pub async fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    JsFuture::from(write_text(text)?).await.map(|_| ())
+}
+
+/// Registers a document-wide `keydown` listener that sends `Msg::CopySelection` on
+/// Ctrl+C or Cmd+C.
+///
+/// The shortcut is ignored while an input element has focus or the page has a text
+/// selection, so normal copying still works, and when it is meant for another screener
+/// on the page. The listener is removed when the returned `EventListener` is dropped.
+pub fn listen_for_copy(link: Scope<Model>, root: NodeRef) -> Option<EventListener> {
+    let window = web_sys::window()?;
+    let document = window.document()?;
+    Some(EventListener::new(&document, "keydown", move |event| {
+        if !is_for_screener(event, &root) {
+            return;
+        }
+        let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+            return;
+        };
+        if !(event.ctrl_key() || event.meta_key()) || !event.key().eq_ignore_ascii_case("c") {
+            return;
+        }
+        let in_input = event
+            .target()
+            .is_some_and(|t| t.dyn_ref::<HtmlInputElement>().is_some());
+        let has_text_selection = window
+            .get_selection()
+            .ok()
+            .flatten()
+            .is_some_and(|s| !s.is_collapsed());
+        if !in_input && !has_text_selection {
+            link.send_message(Msg::CopySelection);
+        }
+    }))
+}
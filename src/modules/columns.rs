@@ -0,0 +1,48 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Columns Module
+//!
+//! This module persists the user's `ColumnLayout` (which columns are shown, in what order,
+//! and how wide) in local storage. The column definitions and the layout itself are
+//! re-exported from `crypto_screener_core::columns`.
+
+use super::storage::{self, Store};
+
+pub use crypto_screener_core::columns::*;
+
+/// Local storage key for the saved column layout.
+const STORAGE_KEY: &str = "columns";
+
+/// Loads the saved layout from local storage, or the default layout if none is saved.
+pub fn load(store: &Store) -> ColumnLayout {
+    storage::load::<ColumnLayout>(store, STORAGE_KEY)
+        .map(ColumnLayout::normalized)
+        .unwrap_or_default()
+}
+
+/// Saves the layout to local storage.
+pub fn save(store: &Store, layout: &ColumnLayout) {
+    storage::save(store, STORAGE_KEY, layout);
+}
@@ -31,26 +31,143 @@
 //! ## Main Components
 //!
 //! - `Model`: The main application state, managing data, sort state, and UI visibility.
+//! - `ScreenerProps`: How a host configures a screener: its initial sort, columns and
+//!   filter, where tickers come from, how often they refresh, and the layout.
 //! - `Msg`: Message types used to trigger updates to the application state and user interactions.
-//! - `COMPONENT_INIT_COUNT`: Tracks component initialization to ensure certain actions (e.g., initial data fetch) 
-//!   run only once.
+//!
+//! Each `Model` fetches and refreshes its own data, so several independently configured
+//! screeners can share a page, e.g.
+//!
+//! ```rust
+//! html! {
+//!     <>
+//!         <Model />
+//!         <Model sort_by={Some("percent_change_24h".to_string())} compact={Some(true)} sync_url={false} />
+//!     </>
+//! }
+//! ```
 
-use std::collections::HashSet;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use yew::prelude::{Component, Context, Html, html};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use gloo_events::EventListener;
+use gloo_timers::callback::Interval;
+use web_sys::{HtmlInputElement, HtmlSelectElement, ScrollIntoViewOptions, ScrollLogicalPosition};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::{Callback, Component, Context, Event, Html, InputEvent, NodeRef, Properties, TargetCast, html};
 use super::about::about_view;
+use super::clipboard::{copy_to_clipboard, listen_for_copy};
+use super::cardview::view_cards;
+use super::chooserview::view_column_chooser;
+use super::columns::{self, ColumnLayout};
 use super::cryptodata::CryptoData;
-use super::fetch::fetch_data;
+use super::currency::{self, Quote, CRYPTO_QUOTES, FIAT_QUOTES};
+use super::export::{download, selection_tsv, timestamped_filename, ExportFormat};
+use super::fetch::tickers_url;
+use super::snapshot::is_data_url;
+use super::filter::Filter;
 use super::headview::view_header;
-use super::sort::sort_data;
+use super::helpview::view_help;
+use super::heatview::view_heatmap;
+use super::history::{self, History};
+use super::i18n::{self, tr, trf, Language};
+use super::keyboard::listen_for_shortcuts;
+use super::sort::{default_ascending, select_sort_column, sort_data};
+use super::statsview::view_stats;
+use super::theme::{self, Theme};
 use super::message::Msg;
-use super::rowview::view_rows;
-use super::utils::toggle_cell_selection;
+use super::numformat::NumberLocale;
+use super::pagination::{self, page_count};
+use super::pageview::view_pagination;
+use super::responsive::{listen_for_resize, window_width};
+use super::rowview::{row_window, view_rows};
+use super::rules::{self, Format, Rule, RuleSet};
+use super::rulesview::view_rules;
+use super::selection::{toggle_cell_selection, toggle_row_selection};
+use super::storage::Store;
+
+/// Default time between automatic data refreshes, in seconds. Each refresh adds a sample
+/// to the recorded history used for technical indicators.
+pub const DEFAULT_REFRESH_SECS: u32 = 60;
+
+/// Assumed height of the table viewport, in pixels, until the user first scrolls it.
+const INITIAL_VIEWPORT_HEIGHT: i32 = 1000;
+
+/// The ways the loaded data can be displayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ViewMode {
+    /// The sortable data table.
+    Table,
+
+    /// The treemap heatmap of `heatview`.
+    Heatmap,
+}
+
+/// Configuration of a screener, set by the host page or application.
+///
+/// Every property has a default, so `<Model />` is the full stand-alone screener. The
+/// sort, columns and filter are where the screener starts; the user can change them as
+/// usual. Changing a property later applies it again.
+#[derive(Properties, Clone, Debug, PartialEq)]
+pub struct ScreenerProps {
+    /// Column to sort by at first, or `None` to keep the API's order.
+    #[prop_or(Some("volume24".to_string()))]
+    pub sort_by: Option<String>,
+
+    /// Initial sort direction, or `None` for the column's `default_ascending` direction.
+    #[prop_or_default]
+    pub sort_asc: Option<bool>,
+
+    /// Keys of the columns to show, in order. `None` uses the layout the user saved;
+    /// a screener given its columns keeps layout changes to itself.
+    #[prop_or_default]
+    pub columns: Option<Vec<String>>,
+
+    /// Initial filter query, in the filter box's syntax.
+    #[prop_or_default]
+    pub filter: String,
+
+    /// Where tickers come from: a Coinlore-format endpoint or an inline `data:` snapshot.
+    /// `None` uses the `tickers_url` query parameter, or the live API.
+    #[prop_or_default]
+    pub provider: Option<String>,
 
-/// Tracks the number of `Model` instances created to ensure initial setup
-/// (such as data fetch) only happens once. This helps prevent redundant 
-/// initialization if the component is accidentally created multiple times.
-static COMPONENT_INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+    /// Seconds between refreshes, or 0 to load the data only once. Snapshots given as a
+    /// `data:` URL are never refreshed.
+    #[prop_or(DEFAULT_REFRESH_SECS)]
+    pub refresh_secs: u32,
+
+    /// `Some(true)` always shows cards and `Some(false)` always shows the table; `None`
+    /// switches to cards on narrow windows.
+    #[prop_or_default]
+    pub compact: Option<bool>,
+
+    /// Whether the page and page size are mirrored in the page URL. Turn this off when
+    /// several screeners share a page, so they don't page each other.
+    #[prop_or(true)]
+    pub sync_url: bool,
+
+    /// Where the column layout, rules, theme, quote currency and language are saved.
+    /// Give each screener on a page its own `Store::Named`, or `Store::Off`, so they
+    /// don't overwrite each other's settings. Read when the screener is created.
+    #[prop_or_default]
+    pub store: Store,
+
+    /// Called with the highlighted `(symbol, column)` cells, in order, whenever the
+    /// highlighting changes.
+    #[prop_or_default]
+    pub onselectionchange: Callback<Vec<(String, String)>>,
+
+    /// Called with the number of coins loaded whenever data has been loaded.
+    #[prop_or_default]
+    pub ondataupdate: Callback<usize>,
+}
+
+impl Default for ScreenerProps {
+    fn default() -> Self {
+        yew::props!(ScreenerProps {})
+    }
+}
 
 /// Represents the main application model, managing the application's core state.
 ///
@@ -58,8 +175,8 @@ static COMPONENT_INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
 /// sorting options, and cell selections. It implements the Yew `Component` trait, 
 /// handling updates and rendering based on incoming messages (`Msg`).
 pub struct Model {
-    /// The list of cryptocurrency data currently loaded.
-    pub data: Vec<CryptoData>,
+    /// The list of cryptocurrency data currently loaded, shared with the row components.
+    pub data: Vec<Rc<CryptoData>>,
     
     /// Holds error messages from failed data fetch attempts, displayed in the UI.
     pub error_message: Option<String>,
@@ -75,31 +192,234 @@ pub struct Model {
     
     /// HashSet of selected cells, represented by unique `(id, column)` pairs.
     pub selected_cells: HashSet<(String, String)>,
+
+    /// Price and volume samples recorded on every fetch, used to compute indicators.
+    pub history: History,
+
+    /// The raw filter query as typed by the user.
+    pub filter_query: String,
+
+    /// The parsed form of `filter_query`, applied when rendering rows.
+    pub filter: Filter,
+
+    /// Which columns are shown, in what order and at what widths.
+    pub columns: ColumnLayout,
+
+    /// Tracks visibility of the column chooser panel.
+    pub show_column_chooser: bool,
+
+    /// Vertical scroll offset of the table viewport, in pixels.
+    pub scroll_top: i32,
+
+    /// Height of the table viewport, in pixels.
+    pub viewport_height: i32,
+
+    /// Rows per page, or `None` to show every row in one scrolling table.
+    pub page_size: Option<usize>,
+
+    /// The 0-based page shown when paging is on.
+    pub page: usize,
+
+    /// Callback shared by every row to toggle a cell; created once so row properties
+    /// stay equal between renders.
+    pub toggle_cell: Callback<(String, String)>,
+
+    /// Conditional formatting rules applied to table cells.
+    pub rules: RuleSet,
+
+    /// Tracks visibility of the formatting rules panel.
+    pub show_rules: bool,
+
+    /// Whether the table or the heatmap is shown.
+    pub view_mode: ViewMode,
+
+    /// Column that sizes heatmap tiles.
+    pub heatmap_size: String,
+
+    /// Percent change column that colours heatmap tiles.
+    pub heatmap_color: String,
+
+    /// The chosen colour theme.
+    pub theme: Theme,
+
+    /// Whether the host page forces the theme, which hides the theme switcher.
+    pub theme_forced: bool,
+
+    /// The language of the interface.
+    pub language: Language,
+
+    /// Thousands and decimal separators for `language`, used by every formatted number.
+    pub number_locale: NumberLocale,
+
+    /// When data was last loaded successfully, in milliseconds since the epoch.
+    pub last_updated: Option<f64>,
+
+    /// Code of the quote currency that prices, volumes and market caps are shown in.
+    pub quote_code: String,
+
+    /// Fiat exchange rates per US dollar, by currency code; empty until fetched.
+    pub rates: HashMap<String, f64>,
+
+    /// The quote that last resolved for `quote_code`, kept so a refresh that loses the
+    /// rate (e.g. tickers without BTC or ETH) does not switch the values back to USD.
+    pub last_quote: Option<Quote>,
+
+    /// Where tickers are loaded from: the live API, the `tickers_url` query parameter, or
+    /// an inline `data:` snapshot.
+    pub tickers_url: String,
+
+    /// Timer that periodically sends `Msg::FetchData`; dropping it stops the refresh.
+    /// There is none for an inline snapshot, which never changes, or when refreshing is off.
+    pub _refresh: Option<Interval>,
+
+    /// Where this screener's settings are loaded from and saved to.
+    pub store: Store,
+
+    /// Whether layout changes are saved as the user's column layout; not when the host
+    /// chose the columns.
+    pub save_columns: bool,
+
+    /// Forced card (`Some(true)`) or table (`Some(false)`) layout, from the `compact` property.
+    pub compact: Option<bool>,
+
+    /// Whether paging is mirrored in the page URL, from the `sync_url` property.
+    pub sync_url: bool,
+
+    /// Reference to the root element, which scopes keyboard shortcuts to this screener.
+    pub root_ref: NodeRef,
+
+    /// Document-wide listener for the Ctrl/Cmd+C copy shortcut, acting on key presses meant
+    /// for this screener; dropping it removes the listener.
+    pub _copy_listener: Option<EventListener>,
+
+    /// Document-wide listener for keyboard shortcuts, acting on key presses meant for this
+    /// screener; dropping it removes the listener.
+    pub _shortcut_listener: Option<EventListener>,
+
+    /// The `(key, column)` of the cell under the keyboard cursor, if it has been shown,
+    /// where `key` is the row's `history::series_key`, so coins sharing a symbol are told
+    /// apart. Like selections, it follows the coin when the rows are re-sorted.
+    pub cursor: Option<(String, String)>,
+
+    /// Set when the cursor moves, so `rendered` scrolls the cursor cell into view.
+    pub scroll_to_cursor: bool,
+
+    /// Tracks visibility of the keyboard shortcut help overlay.
+    pub show_help: bool,
+
+    /// Reference to the filter input, focused by the `/` shortcut.
+    pub search_ref: NodeRef,
+
+    /// Latest status message for screen readers, read out from a polite live region when
+    /// data is refreshed, the sort changes or the filter changes.
+    pub announcement: String,
+
+    /// The window's inner width in CSS pixels, which selects the table or card layout.
+    pub window_width: i32,
+
+    /// Window listener that sends `Msg::Resize`; dropping it removes the listener.
+    pub _resize_listener: Option<EventListener>,
 }
 
 impl Component for Model {
     type Message = Msg;
-    type Properties = ();
+    type Properties = ScreenerProps;
 
-    /// Creates the `Model` component, initializing data and triggering an initial data fetch
-    /// if this is the first instance. Logs a warning if additional instances are created.
+    /// Creates the `Model` component from its properties, triggering the initial data
+    /// fetch and starting the periodic refresh.
     fn create(ctx: &Context<Self>) -> Self {
-        let count = COMPONENT_INIT_COUNT.fetch_add(1, Ordering::SeqCst);
-        if count == 0 {
-            log::info!("Model component created: {}", count);
-            ctx.link().send_message(Msg::FetchData); // Initial data fetch
-        } else {
-            log::warn!("Model component created multiple times: {}", count);
-        }
+        let props = ctx.props();
+        let tickers_url = props.provider.clone().unwrap_or_else(tickers_url);
+        ctx.link().send_message(Msg::FetchData); // Initial data fetch
+        let root_ref = NodeRef::default();
+
+        let (page_size, page) = if props.sync_url { pagination::read_url() } else { (None, 0) };
+        let store = props.store.clone();
+        let quote_code = currency::load_code(&store);
+        let forced_theme = theme::forced();
+        let language = i18n::load(&store);
 
         Self { 
             data: Vec::new(), 
             error_message: None, 
             show_about: false,
-            sort_by: Some("volume24".to_string()), // Initial sort by "volume24"
-            sort_asc: false, // Default to descending
+            sort_by: props.sort_by.clone(),
+            sort_asc: initial_sort_asc(props),
             selected_cells: HashSet::new(),
+            history: History::default(),
+            filter_query: props.filter.clone(),
+            filter: Filter::parse(&props.filter),
+            columns: initial_columns(props, || columns::load(&store)),
+            scroll_top: 0,
+            viewport_height: INITIAL_VIEWPORT_HEIGHT,
+            page_size,
+            page,
+            toggle_cell: ctx.link().callback(|(id, column)| Msg::ToggleCellSelection(id, column)),
+            show_column_chooser: false,
+            rules: rules::load(&store),
+            show_rules: false,
+            view_mode: ViewMode::Table,
+            heatmap_size: "market_cap_usd".to_string(),
+            heatmap_color: "percent_change_24h".to_string(),
+            theme: forced_theme.unwrap_or_else(|| theme::load(&store)),
+            theme_forced: forced_theme.is_some(),
+            language,
+            number_locale: i18n::number_locale(language),
+            last_updated: None,
+            quote_code,
+            rates: HashMap::new(),
+            last_quote: None,
+            _refresh: schedule_refresh(ctx, &tickers_url, props.refresh_secs),
+            tickers_url,
+            store,
+            save_columns: props.columns.is_none(),
+            compact: props.compact,
+            sync_url: props.sync_url,
+            _copy_listener: listen_for_copy(ctx.link().clone(), root_ref.clone()),
+            _shortcut_listener: listen_for_shortcuts(ctx.link().clone(), root_ref.clone()),
+            root_ref,
+            cursor: None,
+            scroll_to_cursor: false,
+            show_help: false,
+            search_ref: NodeRef::default(),
+            announcement: String::new(),
+            window_width: window_width(),
+            _resize_listener: listen_for_resize(ctx.link().clone()),
+        }
+    }
+
+    /// Applies properties the host has changed: a new provider or refresh interval
+    /// reloads the data, and a new sort, column set, filter or layout replaces the
+    /// user's current one. A new filter starts again from the first page, which is
+    /// mirrored in the page URL as when the user filters.
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        self.sync_url = props.sync_url;
+        if props.provider != old_props.provider || props.refresh_secs != old_props.refresh_secs {
+            self.tickers_url = props.provider.clone().unwrap_or_else(tickers_url);
+            self._refresh = schedule_refresh(ctx, &self.tickers_url, props.refresh_secs);
+            if props.provider != old_props.provider {
+                self.history = History::default();
+                ctx.link().send_message(Msg::FetchData);
+            }
+        }
+        if props.sort_by != old_props.sort_by || props.sort_asc != old_props.sort_asc {
+            self.sort_by = props.sort_by.clone();
+            self.sort_asc = initial_sort_asc(props);
+            sort_data(&mut self.data, &self.sort_by, self.sort_asc);
+        }
+        if props.columns != old_props.columns {
+            self.columns = initial_columns(props, || columns::load(&self.store));
+            self.save_columns = props.columns.is_none();
         }
+        if props.filter != old_props.filter {
+            self.filter = Filter::parse(&props.filter);
+            self.filter_query = props.filter.clone();
+            self.page = 0;
+            self.write_page_url();
+        }
+        self.compact = props.compact;
+        true
     }
 
     /// Updates the `Model` state in response to various messages (`Msg`).
@@ -120,84 +440,621 @@ impl Component for Model {
         match msg {
             Msg::FetchData => {
                 log::info!("Fetching data");
+                // Fiat rates drift too, so they are refreshed along with the tickers.
+                if currency::needs_rates(&self.quote_code) {
+                    ctx.link().send_message(Msg::FetchRates);
+                }
                 let link = ctx.link().clone();
+                let url = self.tickers_url.clone();
                 wasm_bindgen_futures::spawn_local(async move {
-                    let data = fetch_data().await;
-                    link.send_message(Msg::SetData(data));
+                    link.send_message(Msg::load_data(&url).await);
                 });
                 false
             }
             Msg::SetData(result) => {
                 match result {
-                    Ok(data) => {
-                        self.data = data;
+                    Ok(mut data) => {
+                        self.history.record(&data);
+                        self.history.apply_indicators(&mut data);
+                        self.data = data.into_iter().map(Rc::new).collect();
+                        self.remember_quote();
                         self.error_message = None;
                         sort_data(&mut self.data, &self.sort_by, self.sort_asc);
+                        self.last_updated = Some(js_sys::Date::now());
+                        self.announcement = trf(self.language, "announce.refreshed", &[&self.data.len()]);
+                        ctx.props().ondataupdate.emit(self.data.len());
                     },
                     Err(err) => {
-                        self.error_message = Some(format!("Failed to fetch data: {:?}", err));
+                        self.error_message = Some(trf(self.language, "error.fetch_data", &[&err]));
                     },
                 }
                 true
             }
             Msg::SortBy(column) => {
-                if self.sort_by.as_ref() == Some(&column) {
-                    self.sort_asc = !self.sort_asc;
-                } else {
-                    self.sort_by = Some(column.clone());
-                    self.sort_asc = column.eq("symbol") || column.eq("name"); // Sort alphabetically ascending
+                select_sort_column(&mut self.sort_by, &mut self.sort_asc, &column);
+                self.apply_sort();
+                true
+            }
+            Msg::SetSort(column, ascending) => {
+                self.sort_by = column;
+                self.sort_asc = ascending;
+                self.apply_sort();
+                true
+            }
+            Msg::ToggleCellSelection(key, column) => {
+                // The clicked cell takes focus, so the keyboard cursor follows it.
+                self.cursor = Some((key.clone(), column.clone()));
+                // Selections are keyed by symbol, as reported to the host page.
+                let Some(symbol) = self.data.iter()
+                    .find(|item| history::series_key(item) == Some(key.as_str()))
+                    .and_then(|item| item.symbol.clone())
+                else {
+                    return true;
+                };
+                toggle_cell_selection(&mut self.selected_cells, symbol, column);
+                ctx.props().onselectionchange.emit(self.selection());
+                true
+            }
+            Msg::ToggleRowSelection(id) => {
+                toggle_row_selection(&mut self.selected_cells, &id, &self.columns.visible_keys());
+                ctx.props().onselectionchange.emit(self.selection());
+                true
+            }
+            Msg::SetViewMode(mode) => {
+                self.view_mode = mode;
+                true
+            }
+            Msg::SetHeatmapSize(column) => {
+                self.heatmap_size = column;
+                true
+            }
+            Msg::SetHeatmapColor(column) => {
+                self.heatmap_color = column;
+                true
+            }
+            Msg::Scroll(scroll_top, viewport_height) => {
+                let row_count = self.visible_rows().count();
+                let before = row_window(self.scroll_top, self.viewport_height, row_count);
+                self.scroll_top = scroll_top;
+                self.viewport_height = viewport_height;
+                row_window(scroll_top, viewport_height, row_count) != before
+            }
+            Msg::SetPageSize(page_size) => {
+                self.page_size = page_size;
+                self.page = 0;
+                self.write_page_url();
+                true
+            }
+            Msg::SetPage(page) => {
+                let total = self.visible_rows().count();
+                self.page = page.min(page_count(total, self.page_size.unwrap_or(total)) - 1);
+                self.write_page_url();
+                true
+            }
+            Msg::ToggleColumnChooser => {
+                self.show_column_chooser = !self.show_column_chooser;
+                true
+            }
+            Msg::ToggleColumn(key) => {
+                self.columns.toggle(&key);
+                self.save_column_layout();
+                true
+            }
+            Msg::MoveColumn(from, to) => {
+                self.columns.move_column(&from, &to);
+                self.save_column_layout();
+                true
+            }
+            Msg::ResizeColumn(key, width) => {
+                self.columns.resize(&key, width);
+                true
+            }
+            Msg::CommitColumnResize => {
+                self.save_column_layout();
+                false
+            }
+            Msg::ResetColumns => {
+                self.columns = initial_columns(ctx.props(), ColumnLayout::default);
+                self.save_column_layout();
+                true
+            }
+            Msg::ToggleRulesPanel => {
+                self.show_rules = !self.show_rules;
+                true
+            }
+            Msg::AddRule => {
+                self.rules.rules.push(Rule {
+                    column: "price_usd".to_string(),
+                    format: Format::SignColor,
+                    enabled: true,
+                });
+                rules::save(&self.store, &self.rules);
+                true
+            }
+            Msg::UpdateRule(index, rule) => {
+                if let Some(existing) = self.rules.rules.get_mut(index) {
+                    *existing = rule;
+                    rules::save(&self.store, &self.rules);
+                }
+                true
+            }
+            Msg::RemoveRule(index) => {
+                if index < self.rules.rules.len() {
+                    self.rules.rules.remove(index);
+                    rules::save(&self.store, &self.rules);
                 }
-                sort_data(&mut self.data, &self.sort_by, self.sort_asc);
                 true
             }
-            Msg::ToggleCellSelection(id, column) => {
-                toggle_cell_selection(&mut self.selected_cells, id, column);
+            Msg::ResetRules => {
+                self.rules = RuleSet::default();
+                rules::save(&self.store, &self.rules);
+                true
+            }
+            Msg::SetQuote(code) => {
+                currency::save_code(&self.store, &code);
+                if currency::needs_rates(&code) && self.rates.is_empty() {
+                    ctx.link().send_message(Msg::FetchRates);
+                }
+                self.quote_code = code;
+                self.remember_quote();
+                true
+            }
+            Msg::FetchRates => {
+                let link = ctx.link().clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    link.send_message(Msg::load_rates(&currency::rates_url()).await);
+                });
+                false
+            }
+            Msg::SetRates(result) => {
+                match result {
+                    Ok(rates) => {
+                        self.rates = rates;
+                        self.remember_quote();
+                    },
+                    Err(err) => {
+                        self.error_message = Some(trf(self.language, "error.fetch_rates", &[&format!("{:?}", err)]));
+                    }
+                }
+                true
+            }
+            Msg::SetTheme(theme) => {
+                if self.theme_forced {
+                    return false;
+                }
+                theme::save(&self.store, theme);
+                self.theme = theme;
+                true
+            }
+            Msg::SetLanguage(language) => {
+                i18n::save(&self.store, language);
+                self.number_locale = i18n::number_locale(language);
+                self.language = language;
+                true
+            }
+            Msg::Resize(width) => {
+                let was_compact = self.is_compact();
+                self.window_width = width;
+                self.is_compact() != was_compact
+            }
+            Msg::SetFilter(query) => {
+                self.filter = Filter::parse(&query);
+                self.filter_query = query;
+                self.announcement = trf(self.language, "announce.matches", &[&self.visible_rows().count()]);
+                if self.page != 0 {
+                    self.page = 0;
+                    self.write_page_url();
+                }
+                true
+            }
+            Msg::Export(format) => {
+                let content = format.render(self.visible_rows(), &self.columns.visible_keys());
+                let filename = timestamped_filename(format);
+                if let Err(err) = download(&filename, format.mime_type(), &content) {
+                    self.error_message = Some(trf(self.language, "error.export", &[&format!("{:?}", err)]));
+                    return true;
+                }
+                false
+            }
+            Msg::CopySelection => {
+                let columns = self.columns.visible_keys();
+                let Some(tsv) = selection_tsv(self.visible_rows(), &columns, &self.selected_cells) else {
+                    return false;
+                };
+                let link = ctx.link().clone();
+                let language = self.language;
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(err) = copy_to_clipboard(&tsv).await {
+                        link.send_message(Msg::ReportError(trf(language, "error.copy", &[&format!("{:?}", err)])));
+                    }
+                });
+                false
+            }
+            Msg::ReportError(message) => {
+                self.error_message = Some(message);
                 true
             }
             Msg::ToggleAbout => {
                 self.show_about = !self.show_about; // Toggle "About" modal visibility
                 true
             }
+            Msg::MoveCursor(dx, dy) => {
+                let rows = self.page_rows();
+                let keys = self.columns.visible_keys();
+                if rows.is_empty() || keys.is_empty() {
+                    return false;
+                }
+                let position = self.cursor.as_ref().and_then(|(id, key)| {
+                    let row = rows.iter().position(|item| history::series_key(item) == Some(id.as_str()))?;
+                    let column = keys.iter().position(|k| k == key)?;
+                    Some((row, column))
+                });
+                let (row, column) = match position {
+                    Some((row, column)) => (
+                        (row as i32 + dy).clamp(0, rows.len() as i32 - 1) as usize,
+                        (column as i32 + dx).clamp(0, keys.len() as i32 - 1) as usize,
+                    ),
+                    // Start on the first row in view; the virtualized table scrolls on its own.
+                    None if self.page_size.is_none() && !self.is_compact() => {
+                        (row_window(self.scroll_top, self.viewport_height, rows.len()).start, 0)
+                    },
+                    None => (0, 0),
+                };
+                let id = history::series_key(rows[row]).unwrap_or_default().to_string();
+                self.cursor = Some((id, keys[column].to_string()));
+                self.scroll_to_cursor = true;
+                true
+            }
+            Msg::ToggleCursorSelection => {
+                if let Some((id, column)) = self.cursor.clone() {
+                    ctx.link().send_message(Msg::ToggleCellSelection(id, column));
+                }
+                false
+            }
+            Msg::SortColumnAt(index) => {
+                if let Some(key) = self.columns.visible_keys().get(index) {
+                    ctx.link().send_message(Msg::SortBy(key.to_string()));
+                }
+                false
+            }
+            Msg::FocusSearch => {
+                if let Some(input) = self.search_ref.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+                false
+            }
+            Msg::ToggleHelp => {
+                self.show_help = !self.show_help;
+                // Help replaces the About dialog rather than stacking on top of it.
+                self.show_about &= !self.show_help;
+                true
+            }
+            Msg::CloseOverlays => {
+                let open = self.show_about || self.show_help;
+                self.show_about = false;
+                self.show_help = false;
+                open
+            }
         }
     }
 
     /// Renders the main view of the application.
     ///
     /// This function creates the application layout, including the title, the
-    /// "About" button, the error message (if any), and the sortable data table.
-    /// It also conditionally renders the "About" modal if `show_about` is `true`.
+    /// "About", export, copy, column and formatting buttons, the quote currency, theme and
+    /// language selectors, the time of the last update, the column chooser and formatting panels (if open), the filter input,
+    /// the error message (if any), the selection statistics status bar, and either the
+    /// sortable data table (or, on narrow screens, cards) or the heatmap. It also
+    /// conditionally renders the "About" modal if `show_about` is `true`, and the keyboard
+    /// help overlay if `show_help` is `true`. The root element carries the theme's
+    /// `data-theme` and the language's `lang`.
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let underscore_line = "_".repeat(130); // For visual structure
+        let columns = self.columns.visible();
+        let table_width: u32 = columns.iter().map(|column| self.columns.width(column)).sum();
+        let lang = self.language;
 
         html! {
-            <div>
+            <div
+                class="crypto-screener"
+                lang={lang.code()}
+                data-theme={self.theme.attribute()}
+                ref={self.root_ref.clone()}
+                tabindex="-1"
+            >
                 <br />
-                <div style="display: flex; align-items: center;">
-                    <h1 style="margin: 0; margin-right: 30px;">{ "WBTek Crypto Screener" }</h1>
-                    <button onclick={ctx.link().callback(|_| Msg::ToggleAbout)}>{ "About" }</button>
+                <div class="toolbar">
+                    <h1>{ tr(lang, "app.title") }</h1>
+                    <button onclick={ctx.link().callback(|_| Msg::ToggleAbout)}>{ tr(lang, "toolbar.about") }</button>
+                    <button title={tr(lang, "toolbar.export_hint")} onclick={ctx.link().callback(|_| Msg::Export(ExportFormat::Csv))}>
+                        { tr(lang, "toolbar.export_csv") }
+                    </button>
+                    <button title={tr(lang, "toolbar.export_hint")} onclick={ctx.link().callback(|_| Msg::Export(ExportFormat::Json))}>
+                        { tr(lang, "toolbar.export_json") }
+                    </button>
+                    <button onclick={ctx.link().callback(|_| Msg::CopySelection)}>{ tr(lang, "toolbar.copy") }</button>
+                    <button onclick={ctx.link().callback(|_| Msg::ToggleColumnChooser)}>{ tr(lang, "toolbar.columns") }</button>
+                    <button onclick={ctx.link().callback(|_| Msg::ToggleRulesPanel)}>{ tr(lang, "toolbar.formatting") }</button>
+                    <select
+                        title={tr(lang, "toolbar.quote")}
+                        onchange={ctx.link().callback(|e: Event| {
+                            Msg::SetQuote(e.target_unchecked_into::<HtmlSelectElement>().value())
+                        })}
+                    >
+                        { for FIAT_QUOTES.iter().chain(CRYPTO_QUOTES.iter()).map(|code| html! {
+                            <option value={*code} selected={self.quote_code == *code}>{ *code }</option>
+                        }) }
+                    </select>
+                    { if self.quote_pending() {
+                        html! { <span class="quote-pending" role="status">{ trf(lang, "toolbar.quote_pending", &[&self.quote_code]) }</span> }
+                    } else {
+                        html! {}
+                    }}
+                    { if self.theme_forced {
+                        html! {}
+                    } else {
+                        html! {
+                            <select
+                                title={tr(lang, "toolbar.theme")}
+                                onchange={ctx.link().callback(|e: Event| {
+                                    let name = e.target_unchecked_into::<HtmlSelectElement>().value();
+                                    Msg::SetTheme(Theme::from_name(&name).unwrap_or_default())
+                                })}
+                            >
+                                { for Theme::ALL.iter().map(|theme| html! {
+                                    <option value={theme.name()} selected={self.theme == *theme}>{ theme.label(lang) }</option>
+                                }) }
+                            </select>
+                        }
+                    }}
+                    <select
+                        title={tr(lang, "toolbar.language")}
+                        onchange={ctx.link().callback(|e: Event| {
+                            let code = e.target_unchecked_into::<HtmlSelectElement>().value();
+                            Msg::SetLanguage(Language::from_code(&code).unwrap_or_default())
+                        })}
+                    >
+                        { for Language::ALL.iter().map(|language| html! {
+                            <option value={language.code()} selected={lang == *language}>{ language.native_name() }</option>
+                        }) }
+                    </select>
+                    { if self.view_mode == ViewMode::Table {
+                        html! { <button onclick={ctx.link().callback(|_| Msg::SetViewMode(ViewMode::Heatmap))}>{ tr(lang, "toolbar.heatmap") }</button> }
+                    } else {
+                        html! { <button onclick={ctx.link().callback(|_| Msg::SetViewMode(ViewMode::Table))}>{ tr(lang, "toolbar.table") }</button> }
+                    }}
+                    { if let Some(time) = self.last_updated {
+                        let time = js_sys::Date::new(&time.into()).to_locale_time_string(lang.code());
+                        html! { <span class="updated">{ trf(lang, "status.updated", &[&String::from(time)]) }</span> }
+                    } else {
+                        html! {}
+                    }}
                 </div>
                 <br />
+                <input
+                    ref={self.search_ref.clone()}
+                    type="search"
+                    placeholder={tr(lang, "filter.placeholder")}
+                    size="50"
+                    value={self.filter_query.clone()}
+                    oninput={ctx.link().callback(|e: InputEvent| {
+                        Msg::SetFilter(e.target_unchecked_into::<HtmlInputElement>().value())
+                    })}
+                />
+                <br />
+                <br />
+                { if self.show_column_chooser {
+                    view_column_chooser(ctx, self)
+                } else {
+                    html! {}
+                }}
+                { if self.show_rules {
+                    view_rules(ctx, self)
+                } else {
+                    html! {}
+                }}
 
                 { if self.show_about {
-                    about_view(ctx)
+                    about_view(ctx, self)
+                } else {
+                    html! {}
+                }}
+                { if self.show_help {
+                    view_help(ctx, self)
                 } else {
                     html! {}
                 }}
      
                 { if let Some(error) = &self.error_message {
-                    html! { <p style="color: red;">{ error }</p> }
+                    html! { <p class="error" role="alert">{ error }</p> }
                 } else {
                     html! {}
                 }}
-                <table>
-                    <thead>
-                        { view_header(ctx, &underscore_line, self) }
-                    </thead>
-                    <tbody>
-                        { view_rows(ctx, self) }
-                    </tbody>
-                </table>
+                <div class="visually-hidden" role="status" aria-live="polite">{ &self.announcement }</div>
+                { view_stats(self) }
+                { if self.view_mode == ViewMode::Heatmap {
+                    view_heatmap(ctx, self)
+                } else if self.is_compact() {
+                    html! {
+                        <>
+                            { view_pagination(ctx, self) }
+                            { view_cards(ctx, self) }
+                        </>
+                    }
+                } else {
+                    html! {
+                        <>
+                            { view_pagination(ctx, self) }
+                            <div
+                                class="table-scroll"
+                                onscroll={ctx.link().callback(|e: Event| {
+                                    let viewport = e.target_unchecked_into::<Element>();
+                                    Msg::Scroll(viewport.scroll_top(), viewport.client_height())
+                                })}
+                            >
+                                <table
+                                    class="data-table"
+                                    role="grid"
+                                    aria-label={tr(lang, "table.label")}
+                                    aria-multiselectable="true"
+                                    aria-rowcount={(self.visible_rows().count() + 1).to_string()}
+                                    style={format!("width: {}px;", table_width)}
+                                >
+                                    <colgroup>
+                                        { for columns.iter().map(|column| html! {
+                                            <col style={format!("width: {}px;", self.columns.width(column))} />
+                                        }) }
+                                    </colgroup>
+                                    <thead>
+                                        { view_header(ctx, self) }
+                                    </thead>
+                                    <tbody>
+                                        { view_rows(ctx, self) }
+                                    </tbody>
+                                </table>
+                            </div>
+                        </>
+                    }
+                }}
             </div>
         }
     }
+
+    /// Scrolls the cell under the keyboard cursor into view and focuses it after the cursor
+    /// has moved, so screen readers follow the cursor through the grid.
+    ///
+    /// The cursor moves one cell at a time, so its row is always within the rendered
+    /// overscan of the virtualized table. CSS `scroll-margin` keeps it clear of the sticky
+    /// header and frozen first column.
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if !std::mem::take(&mut self.scroll_to_cursor) {
+            return;
+        }
+        let cursor = self
+            .root_ref
+            .cast::<Element>()
+            .and_then(|root| root.query_selector(".cursor").ok().flatten());
+        if let Some(cursor) = cursor {
+            let mut options = ScrollIntoViewOptions::new();
+            options.block(ScrollLogicalPosition::Nearest).inline(ScrollLogicalPosition::Nearest);
+            cursor.scroll_into_view_with_scroll_into_view_options(&options);
+            if let Some(cell) = cursor.dyn_ref::<HtmlElement>() {
+                let _ = cell.focus();
+            }
+        }
+    }
+}
+
+/// Returns the initial sort direction: the `sort_asc` property, or the sort column's
+/// default direction.
+fn initial_sort_asc(props: &ScreenerProps) -> bool {
+    props
+        .sort_asc
+        .unwrap_or_else(|| props.sort_by.as_deref().is_some_and(default_ascending))
+}
+
+/// Returns the column layout: the columns given in the properties, or else `fallback`,
+/// e.g. the user's saved layout.
+fn initial_columns(props: &ScreenerProps, fallback: impl FnOnce() -> ColumnLayout) -> ColumnLayout {
+    match &props.columns {
+        Some(keys) => ColumnLayout::showing(keys),
+        None => fallback(),
+    }
+}
+
+/// Starts the timer that refreshes the data every `secs` seconds. There is none when
+/// `secs` is 0 or the tickers are an inline snapshot.
+fn schedule_refresh(ctx: &Context<Model>, tickers_url: &str, secs: u32) -> Option<Interval> {
+    if secs == 0 || is_data_url(tickers_url) {
+        return None;
+    }
+    let link = ctx.link().clone();
+    Some(Interval::new(secs.saturating_mul(1000), move || link.send_message(Msg::FetchData)))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use gloo_timers::future::TimeoutFuture;
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    const TICKERS: &str = include_str!("../../crypto_screener_mock/fixtures/tickers.json");
+
+    /// The recorded tickers as an inline snapshot.
+    fn snapshot() -> Option<String> {
+        Some(format!("data:application/json,{}", String::from(js_sys::encode_uri_component(TICKERS))))
+    }
+
+    /// Mounts a screener with `props` in a new element at the end of the page.
+    fn mount(props: ScreenerProps) -> Element {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.create_element("div").unwrap();
+        document.body().unwrap().append_child(&root).unwrap();
+        yew::Renderer::<Model>::with_root_and_props(root.clone(), props).render();
+        root
+    }
+
+    /// Returns the symbols in the table's first column, top to bottom.
+    fn symbols(root: &Element) -> Vec<String> {
+        let cells = root.query_selector_all("tbody tr[aria-rowindex] td:first-child").unwrap();
+        (0..cells.length())
+            .filter_map(|i| cells.item(i))
+            .filter_map(|cell| cell.text_content())
+            .collect()
+    }
+
+    /// Waits up to two seconds for `ready` to hold, letting the component update.
+    async fn wait_for(ready: impl Fn() -> bool) {
+        for _ in 0..100 {
+            if ready() {
+                return;
+            }
+            TimeoutFuture::new(20).await;
+        }
+        panic!("timed out waiting for the component");
+    }
+
+    /// Mounts the screener on the recorded tickers and drives it through FetchData,
+    /// SetData and SortBy.
+    #[wasm_bindgen_test]
+    async fn loads_and_sorts_a_snapshot() {
+        let root = mount(yew::props!(ScreenerProps { provider: snapshot(), compact: Some(false), sync_url: false }));
+
+        // Sorted by volume, descending, on load.
+        wait_for(|| symbols(&root).len() == 10).await;
+        assert_eq!(symbols(&root)[..3], ["USDT", "BTC", "ETH"]);
+
+        // Text columns sort ascending on the first click.
+        let symbol_header: HtmlElement = root
+            .query_selector("th button.header-label")
+            .unwrap()
+            .unwrap()
+            .dyn_into()
+            .unwrap();
+        symbol_header.click();
+        wait_for(|| symbols(&root).first().map(String::as_str) == Some("ADA")).await;
+        assert_eq!(symbols(&root)[9], "XRP");
+    }
+
+    /// Two differently configured screeners load and sort independently.
+    #[wasm_bindgen_test]
+    async fn screeners_are_independent() {
+        let by_volume = mount(yew::props!(ScreenerProps { provider: snapshot(), compact: Some(false), sync_url: false }));
+        let by_change = mount(yew::props!(ScreenerProps {
+            provider: snapshot(),
+            sort_by: Some("percent_change_7d".to_string()),
+            columns: Some(vec!["symbol".to_string(), "percent_change_7d".to_string()]),
+            filter: "percent_change_7d>0".to_string(),
+            compact: Some(false),
+            sync_url: false,
+        }));
+
+        wait_for(|| symbols(&by_volume).len() == 10 && !symbols(&by_change).is_empty()).await;
+        assert_eq!(symbols(&by_volume)[0], "USDT");
+        assert_eq!(symbols(&by_change), ["DOGE", "SOL", "BNB", "BTC", "ADA", "TRX", "USDT"]);
+        assert_eq!(by_change.query_selector_all("thead th").unwrap().length(), 2);
+    }
 }
@@ -0,0 +1,67 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Quote Currency Module
+//!
+//! This module holds the browser side of quote currencies: where fiat rates are fetched
+//! from, which can be pointed at a local file with the `rates_url` query parameter for
+//! offline testing, and the saved choice of currency. The conversion itself, `Quote`, is
+//! re-exported from `crypto_screener_core::currency`.
+//!
+//! ## Key Items
+//!
+//! - `rates_url`: The endpoint used by `fetch_rates`.
+//! - `load_code` / `save_code`: The quote currency kept in local storage.
+
+use web_sys::UrlSearchParams;
+use super::storage::{self, Store};
+
+pub use crypto_screener_core::currency::*;
+
+/// Query parameter overriding `DEFAULT_RATES_URL`, e.g. `?rates_url=rates.json`.
+const RATES_URL_PARAM: &str = "rates_url";
+
+/// Local storage key for the selected quote currency.
+const STORAGE_KEY: &str = "quote";
+
+/// Returns the rates endpoint, taken from the `rates_url` query parameter if present.
+pub fn rates_url() -> String {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .and_then(|search| UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get(RATES_URL_PARAM))
+        .unwrap_or_else(|| DEFAULT_RATES_URL.to_string())
+}
+
+/// Loads the saved quote currency code, defaulting to USD.
+pub fn load_code(store: &Store) -> String {
+    storage::load::<String>(store, STORAGE_KEY)
+        .filter(|code| is_supported(code))
+        .unwrap_or_else(|| "USD".to_string())
+}
+
+/// Saves the selected quote currency code.
+pub fn save_code(store: &Store, code: &str) {
+    storage::save(store, STORAGE_KEY, &code);
+}
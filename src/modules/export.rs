@@ -0,0 +1,95 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Export Module
+//!
+//! This module hands exported rows to the browser as a file download. Rendering rows as
+//! CSV or JSON, and highlighted cells as a tab-separated grid, is re-exported from
+//! `crypto_screener_core::export`.
+//!
+//! ## Key Functions
+//!
+//! - `timestamped_filename`: Builds a download filename stamped with the current UTC time.
+//! - `download`: Saves text content through a client-side `Blob` download.
+
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+pub use crypto_screener_core::export::*;
+
+/// Milliseconds to keep a download's object URL alive after its link is clicked.
+const REVOKE_DELAY_MS: u32 = 1_000;
+
+/// Builds a filename such as `crypto_screener_20241031T142500Z.csv` from the current UTC time.
+pub fn timestamped_filename(format: ExportFormat) -> String {
+    let now = js_sys::Date::new_0();
+    format!(
+        "crypto_screener_{:04}{:02}{:02}T{:02}{:02}{:02}Z.{}",
+        now.get_utc_full_year(),
+        now.get_utc_month() + 1,
+        now.get_utc_date(),
+        now.get_utc_hours(),
+        now.get_utc_minutes(),
+        now.get_utc_seconds(),
+        format.extension(),
+    )
+}
+
+/// Offers `content` to the user as a file download named `filename`.
+///
+/// The content is wrapped in a `Blob` and linked from a temporary anchor element, which is
+/// attached to the page just long enough to be clicked programmatically. The object URL
+/// is revoked once the browser has had a chance to start the download; Firefox and older
+/// Safari drop downloads whose URL is revoked straight after the click.
+///
+/// # Errors
+///
+/// Returns the JavaScript exception if the browser window, document, or any of the
+/// Blob/URL APIs are unavailable.
+pub fn download(filename: &str, mime_type: &str, content: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("No document available"))?;
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let mut options = BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    let body = document.body().ok_or_else(|| JsValue::from_str("No document body available"))?;
+    body.append_child(&anchor)?;
+    anchor.click();
+    anchor.remove();
+
+    Timeout::new(REVOKE_DELAY_MS, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
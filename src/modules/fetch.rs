@@ -26,27 +26,86 @@
 //!
 //! This module provides functionality to fetch cryptocurrency data from an external API.
 //! The `fetch_data` function asynchronously requests data and parses it into a collection
-//! of `CryptoData` structs for use within the application.
+//! of `CryptoData` structs for use within the application, and `fetch_rates` requests
+//! fiat exchange rates for quote currency conversion.
+//!
+//! The ticker endpoint can be replaced with the `tickers_url` query parameter, either to
+//! point at a mock server or a recorded snapshot file, or to pass a snapshot inline as a
+//! `data:` URL; see `crypto_screener_core::snapshot`.
 
+use std::collections::HashMap;
+use std::fmt;
 use reqwest::Client;
 use serde_json::Value;
-use super::cryptodata::CryptoData;
+use web_sys::UrlSearchParams;
+use super::cryptodata::{parse_tickers, CryptoData, DEFAULT_TICKERS_URL};
+use super::snapshot::{decode_data_url, is_data_url, parse_snapshot, SnapshotError};
+
+/// Query parameter overriding `DEFAULT_TICKERS_URL`, e.g. `?tickers_url=snapshot.json`.
+const TICKERS_URL_PARAM: &str = "tickers_url";
+
+/// Why tickers could not be loaded.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The HTTP request failed, the server answered with an error status such as 429,
+    /// or the body was not JSON.
+    Request(reqwest::Error),
+
+    /// An inline `data:` snapshot could not be read.
+    Snapshot(SnapshotError),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Request(err) => write!(f, "{}", err),
+            FetchError::Snapshot(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> Self {
+        FetchError::Request(err)
+    }
+}
+
+impl From<SnapshotError> for FetchError {
+    fn from(err: SnapshotError) -> Self {
+        FetchError::Snapshot(err)
+    }
+}
+
+/// Returns the ticker endpoint, taken from the `tickers_url` query parameter if present.
+pub fn tickers_url() -> String {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .and_then(|search| UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get(TICKERS_URL_PARAM))
+        .unwrap_or_else(|| DEFAULT_TICKERS_URL.to_string())
+}
 
 /// Fetches cryptocurrency data from an external API and parses it into a vector of `CryptoData` structs.
 ///
 /// This function uses `reqwest` to send an HTTP GET request to a cryptocurrency API,
-/// retrieves JSON data, and attempts to deserialize it into a list of `CryptoData` entries.
-/// If the data is unavailable or does not contain expected fields, an empty vector is returned.
+/// retrieves JSON data, and deserializes it into a list of `CryptoData` entries with
+/// `parse_tickers`. If the data is unavailable or does not contain expected fields, an
+/// empty vector is returned. A `data:` URL is decoded in place instead of being
+/// requested, and its payload parsed the same way.
+///
+/// # Parameters
+///
+/// - `url`: The ticker endpoint, usually from `tickers_url`.
 ///
 /// # Returns
 ///
 /// - `Ok(Vec<CryptoData>)`: A vector of `CryptoData` instances if the data is fetched and parsed successfully.
-/// - `Err(reqwest::Error)`: An error if the request or JSON parsing fails.
+/// - `Err(FetchError)`: An error if the request, the `data:` URL or JSON parsing fails.
 ///
 /// # Example
 ///
 /// ```rust
-/// let data = fetch_data().await?;
+/// let data = fetch_data(&tickers_url()).await?;
 /// for crypto in data {
 ///     println!("{:?}", crypto);
 /// }
@@ -54,32 +113,135 @@ use super::cryptodata::CryptoData;
 ///
 /// # Errors
 ///
-/// Returns a `reqwest::Error` if the HTTP request or JSON deserialization fails.
+/// Returns `FetchError::Request` if the HTTP request fails, the server answers with an
+/// error status (a rate-limited 429 included) or JSON deserialization fails, and
+/// `FetchError::Snapshot` if a `data:` URL is malformed or does not hold JSON.
 ///
 /// # API Endpoint
 ///
-/// The function currently fetches data from:
+/// Unless overridden, the function fetches data from `DEFAULT_TICKERS_URL`,
 /// `https://api.coinlore.net/api/tickers/`. It retrieves general market information
 /// for various cryptocurrencies. An alternative API URL is commented out in the code.
-pub async fn fetch_data() -> Result<Vec<CryptoData>, reqwest::Error> {
+pub async fn fetch_data(url: &str) -> Result<Vec<CryptoData>, FetchError> {
+    if is_data_url(url) {
+        return Ok(parse_snapshot(&decode_data_url(url)?)?);
+    }
     let client = Client::new();
     let res = client
         // .get("https://api.coingecko.com/api/v3/coins/markets?vs_currency=usd")
-        .get("https://api.coinlore.net/api/tickers/")
+        .get(url)
         .send()
         .await?
+        .error_for_status()?
         .json::<Value>()
         .await?;
+    Ok(parse_tickers(&res))
+}
+
+/// Fetches fiat exchange rates relative to one US dollar.
+///
+/// The endpoint must return a JSON object with a `rates` map of currency codes to
+/// numbers, e.g. `{"rates": {"EUR": 0.92}}`. Non-numeric entries are skipped, and a
+/// missing `rates` field yields an empty map.
+///
+/// # Parameters
+///
+/// - `url`: The rates endpoint, usually from `currency::rates_url`.
+///
+/// # Example
+///
+/// ```rust
+/// let rates = fetch_rates("rates.json").await?;
+/// let eur = rates.get("EUR");
+/// ```
+///
+/// # Errors
+///
+/// Returns a `reqwest::Error` if the HTTP request or JSON deserialization fails.
+pub async fn fetch_rates(url: &str) -> Result<HashMap<String, f64>, reqwest::Error> {
+    let res = Client::new().get(url).send().await?.json::<Value>().await?;
+    let rates = res
+        .get("rates")
+        .and_then(|r| r.as_object())
+        .map(|rates| {
+            rates
+                .iter()
+                .filter_map(|(code, rate)| Some((code.clone(), rate.as_f64()?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(rates)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crypto_screener_mock::MockServer;
+    use crypto_screener_core::sort::sort_data;
+
+    fn symbols(data: &[CryptoData]) -> Vec<&str> {
+        data.iter().filter_map(|item| item.symbol.as_deref()).collect()
+    }
+
+    #[tokio::test]
+    async fn fetches_and_sorts_tickers() {
+        let server = MockServer::start().unwrap();
+        let mut data = fetch_data(&server.url("/api/tickers/")).await.unwrap();
+        assert_eq!(data.len(), 10);
+
+        sort_data(&mut data, &Some("volume24".to_string()), false);
+        assert_eq!(symbols(&data)[..3], ["USDT", "BTC", "ETH"]);
+        sort_data(&mut data, &Some("percent_change_24h".to_string()), false);
+        assert_eq!(symbols(&data)[..2], ["DOGE", "ETH"]);
+    }
+
+    #[tokio::test]
+    async fn fetches_one_page() {
+        let server = MockServer::start().unwrap();
+        let first = fetch_data(&server.url("/api/tickers/?start=0&limit=4")).await.unwrap();
+        let second = fetch_data(&server.url("/api/tickers/?start=4&limit=4")).await.unwrap();
+        assert_eq!(symbols(&first), ["BTC", "ETH", "USDT", "BNB"]);
+        assert_eq!(symbols(&second), ["SOL", "XRP", "DOGE", "ADA"]);
+    }
+
+    #[tokio::test]
+    async fn empty_response_has_no_rows() {
+        let server = MockServer::start().unwrap();
+        assert!(fetch_data(&server.url("/empty/api/tickers/")).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_malformed_and_rate_limited_responses() {
+        let server = MockServer::start().unwrap();
+        match fetch_data(&server.url("/malformed/api/tickers/")).await {
+            Err(FetchError::Request(err)) => assert!(err.is_decode()),
+            other => panic!("expected a decode error, got {:?}", other.map(|data| data.len())),
+        }
+        match fetch_data(&server.url("/rate-limited/api/tickers/")).await {
+            Err(FetchError::Request(err)) => assert_eq!(err.status().map(|s| s.as_u16()), Some(429)),
+            other => panic!("expected status 429, got {:?}", other.map(|data| data.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn fetches_rates() {
+        let server = MockServer::start().unwrap();
+        let rates = fetch_rates(&server.url("/v6/latest/USD")).await.unwrap();
+        assert_eq!(rates.len(), 8);
+        assert_eq!(rates.get("EUR"), Some(&0.92));
+        assert_eq!(rates.get("JPY"), Some(&151.3));
+    }
+
+    #[tokio::test]
+    async fn rates_without_a_rates_map_are_empty_and_malformed_rates_fail() {
+        let server = MockServer::start().unwrap();
+        assert!(fetch_rates(&server.url("/api/tickers/")).await.unwrap().is_empty());
+        assert!(fetch_rates(&server.url("/malformed/api/tickers/")).await.unwrap_err().is_decode());
+    }
 
-    // Extracts and parses the "data" field into `CryptoData` structs if available.
-    if let Some(array) = res.get("data").and_then(|d| d.as_array()) {
-        let data: Vec<CryptoData> = array
-            .iter()
-            .filter_map(|item| serde_json::from_value(item.clone()).ok())
-            .collect();
-        Ok(data)
-    } else {
-        // Returns an empty vector if "data" is missing or has invalid format
-        Ok(Vec::new())
+    #[tokio::test]
+    async fn decodes_data_url_like_a_response() {
+        let url = format!("data:application/json,{}", crypto_screener_mock::EMPTY.replace(' ', "%20"));
+        assert!(fetch_data(&url).await.unwrap().is_empty());
     }
 }
@@ -35,18 +35,18 @@ use super::message::Msg;
 
 /// Renders a table header with sortable columns for cryptocurrency data.
 ///
-/// This function creates a row of `HeaderButton` components representing different data columns
-/// (such as "Symbol," "Name," "Price (USD)," and percentage changes over various periods). Each
-/// button displays a label and triggers a sorting action when clicked, updating the application's 
-/// sorting state based on the selected column.
+/// This function creates a row of `HeaderButton` components, one for each visible column of
+/// the model's [`ColumnLayout`](super::columns::ColumnLayout), in the user's chosen order.
+/// Each button displays the column's label and triggers a sorting action when clicked,
+/// updating the application's sorting state based on the selected column. Headers can also
+/// be dragged onto each other to reorder columns, and resized from their right edge.
 ///
 /// # Parameters
 ///
 /// - `ctx`: Reference to the Yew [`Context`] for the main application [`Model`], which allows
 ///   interaction with component links for handling click events.
-/// - `underscore_line`: A string of underscores used as a visual separator under the header row.
-/// - `model`: A reference to the main application model, used to retrieve the current sorting order
-///   for each column.
+/// - `model`: A reference to the main application model, used to retrieve the column layout
+///   and the current sorting order for each column.
 ///
 /// # Returns
 ///
@@ -58,58 +58,37 @@ use super::message::Msg;
 /// - A label indicating the column's name.
 /// - A `sort_order` representing the current sorting state for the column (ascending or descending).
 /// - An `onclick` callback that sends a `Msg::SortBy` message to update the sort order.
+/// - `ondropcolumn` and `onresize` callbacks that send `Msg::MoveColumn` and `Msg::ResizeColumn`,
+///   and an `onresizeend` callback that sends `Msg::CommitColumnResize`.
+///
+/// The header row is sticky, and its first cell frozen, through the `.data-table` rules in
+/// `styles.css`; a bottom border separates it from the rows.
 ///
 /// # Example
 ///
 /// ```rust
-/// let underscore_line = "_".repeat(130);
-/// let header = view_header(ctx, &underscore_line, model);
+/// let header = view_header(ctx, model);
 /// ```
-
-pub fn view_header(ctx: &Context<Model>, underscore_line: &str, model: &Model) -> Html {
+pub fn view_header(ctx: &Context<Model>, model: &Model) -> Html {
     let link = ctx.link();
+    let columns = model.columns.visible();
+    let quote = model.quote();
     html! {
-        <>
-            <tr>
-                <HeaderButton
-                    label={"Symbol".to_string()}
-                    sort_order={model.sort_order("symbol")}
-                    onclick={link.callback(|_| Msg::SortBy("symbol".to_string()))}
-                />
-                <HeaderButton
-                    label={"Name".to_string()}
-                    sort_order={model.sort_order("name")}
-                    onclick={link.callback(|_| Msg::SortBy("name".to_string()))}
-                />
-                <HeaderButton
-                    label={"Price (USD)".to_string()}
-                    sort_order={model.sort_order("price_usd")}
-                    onclick={link.callback(|_| Msg::SortBy("price_usd".to_string()))}
-                />
-                <HeaderButton
-                    label={"1h %".to_string()}
-                    sort_order={model.sort_order("percent_change_1h")}
-                    onclick={link.callback(|_| Msg::SortBy("percent_change_1h".to_string()))}
-                />
-                <HeaderButton
-                    label={"24h %".to_string()}
-                    sort_order={model.sort_order("percent_change_24h")}
-                    onclick={link.callback(|_| Msg::SortBy("percent_change_24h".to_string()))}
-                />
-                <HeaderButton
-                    label={"7d %".to_string()}
-                    sort_order={model.sort_order("percent_change_7d")}
-                    onclick={link.callback(|_| Msg::SortBy("percent_change_7d".to_string()))}
-                />
-                <HeaderButton
-                    label={"Volume ($)".to_string()}
-                    sort_order={model.sort_order("volume24")}
-                    onclick={link.callback(|_| Msg::SortBy("volume24".to_string()))}
-                />
-            </tr>
-            <tr>
-                <th colspan="7" style="text-align: left;">{ underscore_line }</th>
-            </tr>
-        </>
+        <tr aria-rowindex="1">
+            { for columns.iter().map(|column| {
+                let key = column.key;
+                html! {
+                    <HeaderButton
+                        label={column.title(&quote, model.language)}
+                        sort_order={model.sort_order(key)}
+                        onclick={link.callback(move |_| Msg::SortBy(key.to_string()))}
+                        column={key.to_string()}
+                        ondropcolumn={link.callback(move |from: String| Msg::MoveColumn(from, key.to_string()))}
+                        onresize={link.callback(move |width: u32| Msg::ResizeColumn(key.to_string(), width))}
+                        onresizeend={link.callback(|_| Msg::CommitColumnResize)}
+                    />
+                }
+            }) }
+        </tr>
     }
 }
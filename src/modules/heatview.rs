@@ -0,0 +1,186 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Heatmap View Module
+//!
+//! This module provides the `view_heatmap` function, an alternative to the data table that
+//! renders the filtered cryptocurrencies as an SVG treemap. Tile areas are proportional to
+//! market cap or 24-hour volume, and tile colours follow the chosen percent change window,
+//! from red (falling) through grey to green (rising).
+//!
+//! Clicking a tile toggles the selection of that coin's whole row, just as if every cell of
+//! the row had been clicked in the table.
+
+use web_sys::HtmlSelectElement;
+use yew::prelude::{Callback, Context, Event, Html, TargetCast, html};
+use super::component::Model;
+use super::i18n::{column_label, tr, Language};
+use super::message::Msg;
+use super::treemap::{squarify, Rect};
+
+/// Width of the SVG coordinate space.
+const WIDTH: f64 = 1000.0;
+
+/// Height of the SVG coordinate space.
+const HEIGHT: f64 = 600.0;
+
+/// Percent change at which tile colours reach full saturation.
+const FULL_SCALE_PERCENT: f64 = 10.0;
+
+/// Columns that can size tiles, with their English labels.
+pub const SIZE_OPTIONS: [(&str, &str); 2] = [
+    ("market_cap_usd", "Market cap"),
+    ("volume24", "Volume (24h)"),
+];
+
+/// Columns that can colour tiles, with their English labels.
+pub const COLOR_OPTIONS: [(&str, &str); 3] = [
+    ("percent_change_1h", "1h %"),
+    ("percent_change_24h", "24h %"),
+    ("percent_change_7d", "7d %"),
+];
+
+/// Renders the heatmap controls and the treemap of visible rows.
+///
+/// Rows without a positive value for the size column are left out.
+///
+/// # Example
+///
+/// ```rust
+/// let heatmap = view_heatmap(ctx, model);
+/// ```
+pub fn view_heatmap(ctx: &Context<Model>, model: &Model) -> Html {
+    let link = ctx.link();
+    let lang = model.language;
+    let mut tiles: Vec<(String, f64, Option<f64>)> = model
+        .visible_rows()
+        .filter_map(|item| {
+            let size = item.numeric_value(&model.heatmap_size).filter(|v| *v > 0.0)?;
+            let symbol = item.symbol.clone().unwrap_or_default();
+            Some((symbol, size, item.numeric_value(&model.heatmap_color)))
+        })
+        .collect();
+    tiles.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let visible_keys = model.columns.visible_keys();
+    let weights: Vec<f64> = tiles.iter().map(|t| t.1).collect();
+    let rects = squarify(&weights, Rect { x: 0.0, y: 0.0, w: WIDTH, h: HEIGHT });
+
+    html! {
+        <div class="heatmap">
+            <div class="heatmap-controls">
+                { tr(lang, "heatmap.size") }
+                { " " }
+                { view_select(lang, &SIZE_OPTIONS, &model.heatmap_size,
+                    link.callback(|e: Event| Msg::SetHeatmapSize(select_value(e)))) }
+                { format!(" {} ", tr(lang, "heatmap.color")) }
+                { view_select(lang, &COLOR_OPTIONS, &model.heatmap_color,
+                    link.callback(|e: Event| Msg::SetHeatmapColor(select_value(e)))) }
+            </div>
+            <svg viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)} width="100%">
+                { for tiles.iter().zip(rects.iter()).map(|((symbol, _, change), rect)| {
+                    let selected = visible_keys.iter().all(|column| {
+                        model.selected_cells.contains(&(symbol.clone(), column.to_string()))
+                    });
+                    let onclick = link.callback({
+                        let symbol = symbol.clone();
+                        move |_| Msg::ToggleRowSelection(symbol.clone())
+                    });
+                    let change_text = change.map(|c| format!("{:+.2}%", c)).unwrap_or_default();
+                    html! {
+                        <g class={if selected { "tile selected" } else { "tile" }} onclick={onclick}>
+                            <title>{ format!("{} {}", symbol, change_text) }</title>
+                            <rect
+                                x={rect.x.to_string()}
+                                y={rect.y.to_string()}
+                                width={rect.w.to_string()}
+                                height={rect.h.to_string()}
+                                fill={change_color(*change)}
+                            />
+                            { view_label(rect, symbol, &change_text) }
+                        </g>
+                    }
+                }) }
+            </svg>
+        </div>
+    }
+}
+
+/// Renders a `<select>` of `options` labelled in `lang`, with `current` selected.
+fn view_select(lang: Language, options: &[(&'static str, &'static str)], current: &str, onchange: Callback<Event>) -> Html {
+    html! {
+        <select onchange={onchange}>
+            { for options.iter().map(|(value, label)| html! {
+                <option value={*value} selected={*value == current}>{ column_label(lang, value, label) }</option>
+            }) }
+        </select>
+    }
+}
+
+/// Reads the value of the `<select>` element that fired `e`.
+fn select_value(e: Event) -> String {
+    e.target_unchecked_into::<HtmlSelectElement>().value()
+}
+
+/// Renders the symbol and percent change centred in a tile, if the tile is large enough.
+fn view_label(rect: &Rect, symbol: &str, change_text: &str) -> Html {
+    if rect.w < 30.0 || rect.h < 16.0 {
+        return html! {};
+    }
+    let font_size = (rect.w / 5.0).min(rect.h / 3.0).min(32.0);
+    let cx = rect.x + rect.w / 2.0;
+    let cy = rect.y + rect.h / 2.0;
+    html! {
+        <text
+            x={cx.to_string()}
+            y={cy.to_string()}
+            font-size={font_size.to_string()}
+            text-anchor="middle"
+        >
+            <tspan x={cx.to_string()}>{ symbol }</tspan>
+            { if rect.h >= font_size * 2.5 {
+                html! { <tspan x={cx.to_string()} dy="1.2em">{ change_text }</tspan> }
+            } else {
+                html! {}
+            }}
+        </text>
+    }
+}
+
+/// Maps a percent change to a fill colour: grey at zero, shading to green for gains and
+/// red for losses, saturating at `FULL_SCALE_PERCENT`. Missing values are grey.
+fn change_color(change: Option<f64>) -> String {
+    let (grey, green, red) = ((85.0, 85.0, 85.0), (0.0, 160.0, 60.0), (200.0, 30.0, 30.0));
+    let Some(change) = change else {
+        return "rgb(85, 85, 85)".to_string();
+    };
+    let t = (change.abs() / FULL_SCALE_PERCENT).min(1.0);
+    let target = if change >= 0.0 { green } else { red };
+    let mix = |from: f64, to: f64| (from + (to - from) * t).round();
+    format!(
+        "rgb({}, {}, {})",
+        mix(grey.0, target.0),
+        mix(grey.1, target.1),
+        mix(grey.2, target.2),
+    )
+}
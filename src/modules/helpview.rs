@@ -0,0 +1,63 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Help Overlay Module
+//!
+//! This module provides the `view_help` function, which renders a modal listing the
+//! keyboard shortcuts from `keyboard::SHORTCUTS`. It is opened with `?` and closed with
+//! `?`, Escape or its close button.
+
+use yew::{html, Html, Context};
+use super::component::Model;
+use super::i18n::tr;
+use super::keyboard::SHORTCUTS;
+use super::message::Msg;
+use super::modal::Modal;
+
+/// Renders the keyboard shortcut help overlay.
+///
+/// # Example
+///
+/// ```rust
+/// let help = view_help(ctx, model);
+/// ```
+pub fn view_help(ctx: &Context<Model>, model: &Model) -> Html {
+    let lang = model.language;
+    html! {
+        <Modal
+            title={tr(lang, "help.title")}
+            close_label={tr(lang, "modal.close")}
+            onclose={ctx.link().callback(|_| Msg::ToggleHelp)}
+        >
+            <table class="shortcuts">
+                { for SHORTCUTS.iter().map(|(keys, action)| html! {
+                    <tr>
+                        <th scope="row"><kbd>{ tr(lang, keys) }</kbd></th>
+                        <td>{ tr(lang, action) }</td>
+                    </tr>
+                }) }
+            </table>
+        </Modal>
+    }
+}
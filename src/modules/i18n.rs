@@ -0,0 +1,79 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Internationalization Module
+//!
+//! This module keeps the language switcher's state in the browser: the saved or browser
+//! language and the number separators that go with it. The screener's root element carries
+//! the language as its `lang` attribute, leaving the host page's own `<html lang>` alone. The
+//! message catalogue and `tr`/`trf` lookups are re-exported from
+//! `crypto_screener_core::i18n`.
+
+use super::numformat::NumberLocale;
+use super::storage::{self, Store};
+
+pub use crypto_screener_core::i18n::*;
+
+/// Local storage key for the chosen language.
+const STORAGE_KEY: &str = "language";
+
+/// Returns the saved language, or else the browser's language if it is supported, or
+/// else English.
+pub fn load(store: &Store) -> Language {
+    storage::load(store, STORAGE_KEY)
+        .or_else(|| browser_language().and_then(|tag| Language::from_code(&tag)))
+        .unwrap_or_default()
+}
+
+/// Saves the chosen language.
+pub fn save(store: &Store, lang: Language) {
+    storage::save(store, STORAGE_KEY, &lang);
+}
+
+/// Returns the number separators for `lang`. The browser's own regional variant is
+/// preferred when it matches, so an en-GB browser keeps en-GB conventions in English.
+pub fn number_locale(lang: Language) -> NumberLocale {
+    let tag = browser_language()
+        .filter(|tag| Language::from_code(tag) == Some(lang))
+        .unwrap_or_else(|| lang.code().to_string());
+    detect_locale(&tag)
+}
+
+/// Returns the browser's preferred language tag, e.g. `"en-US"`.
+fn browser_language() -> Option<String> {
+    web_sys::window()?.navigator().language()
+}
+
+/// Returns the separators the browser uses for a BCP 47 language tag, such as `"de"` or
+/// `"en-GB"`. Outside the browser the en-US default is always used.
+#[cfg(target_arch = "wasm32")]
+fn detect_locale(tag: &str) -> NumberLocale {
+    let sample = String::from(js_sys::Number::from(12345.6).to_locale_string(tag));
+    NumberLocale::from_sample(&sample).unwrap_or_default()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn detect_locale(_tag: &str) -> NumberLocale {
+    NumberLocale::default()
+}
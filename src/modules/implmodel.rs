@@ -32,13 +32,32 @@
 //! ## Key Methods
 //!
 //! - `sort_order`: Determines the current sort order for a specified column.
+//! - `apply_sort`: Sorts the data by the current sort column and announces the new order.
+//! - `visible_rows`: Iterates over the data rows that pass the active filter.
+//! - `visible_shared_rows`: The same rows as shared `Rc` handles, for memoized row components.
+//! - `page_rows`: The visible rows on the current page, or all of them when not paging.
+//! - `quote`: The selected quote currency with its current rate.
+//! - `cell_style`: The inline style of a cell, combining formatting rules and selection.
+//! - `is_compact`: Whether coins are shown as cards rather than the table.
+//! - `selection`: The highlighted cells, in a stable order, as reported to the host.
+//! - `write_page_url` / `save_column_layout`: Persist paging and the column layout, where
+//!   the host allows it.
 //!
 //! These methods are designed to work seamlessly with the rest of the application by encapsulating
 //! state management logic within the `Model` struct, making it easier to maintain and modify behavior
 //! related to sorting, data processing, and user interactions.
 
 use super::button::SortOrder;
+use super::columns;
+use std::rc::Rc;
 use super::component::Model;
+use super::cryptodata::CryptoData;
+use super::currency::Quote;
+use super::pagination::{self, page_range};
+use super::i18n::{tr, trf};
+use super::responsive::CARD_BREAKPOINT;
+use super::sort::sort_data;
+use super::utils;
 
 impl Model {
     /// Determines the current sort order for a specified column.
@@ -81,4 +100,115 @@ impl Model {
         }
         SortOrder::None
     }
+
+    /// Sorts the data by `sort_by` in the `sort_asc` direction, and sets the live-region
+    /// announcement so screen readers hear the new order, whether the user or the host
+    /// page changed it.
+    pub fn apply_sort(&mut self) {
+        sort_data(&mut self.data, &self.sort_by, self.sort_asc);
+        let Some(column) = &self.sort_by else {
+            return;
+        };
+        let label = columns::column(column)
+            .map(|c| c.title(&self.quote(), self.language))
+            .unwrap_or_else(|| column.clone());
+        let direction = tr(self.language, if self.sort_asc { "announce.ascending" } else { "announce.descending" });
+        self.announcement = trf(self.language, "announce.sorted", &[&label, &direction]);
+    }
+
+    /// Iterates over the rows of `data` that pass the active filter, in the current sort order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// for item in model.visible_rows() {
+    ///     log::info!("{:?}", item.symbol);
+    /// }
+    /// ```
+    pub fn visible_rows(&self) -> impl Iterator<Item = &CryptoData> {
+        self.visible_shared_rows().map(|item| item.as_ref())
+    }
+
+    /// Iterates over the shared handles of the rows that pass the active filter, in the
+    /// current sort order.
+    ///
+    /// Row components receive these handles as properties, so unchanged rows compare equal
+    /// and are not re-rendered.
+    pub fn visible_shared_rows(&self) -> impl Iterator<Item = &Rc<CryptoData>> {
+        self.data.iter().filter(|item| self.filter.matches(item))
+    }
+
+    /// Returns the shared handles of the visible rows on the current page, or of all visible
+    /// rows when paging is off.
+    pub fn page_rows(&self) -> Vec<&Rc<CryptoData>> {
+        let rows: Vec<_> = self.visible_shared_rows().collect();
+        match self.page_size {
+            Some(page_size) => rows[page_range(self.page, page_size, rows.len())].to_vec(),
+            None => rows,
+        }
+    }
+
+    /// Returns the selected quote currency with its current rate.
+    ///
+    /// Uses the last rate that resolved if the current data has none. Falls back to USD
+    /// only until a first rate is known, i.e. before fiat rates have been fetched or before
+    /// the BTC/ETH tickers have loaded; `quote_pending` tells the toolbar to say so.
+    pub fn quote(&self) -> Quote {
+        Quote::resolve(&self.quote_code, &self.rates, &self.data)
+            .or_else(|| self.last_quote.clone().filter(|quote| quote.code == self.quote_code))
+            .unwrap_or_default()
+    }
+
+    /// Whether values are shown in USD because the selected currency has no rate yet.
+    pub fn quote_pending(&self) -> bool {
+        self.quote().code != self.quote_code
+    }
+
+    /// Keeps the current rate of the selected quote currency for `quote`, if it resolves.
+    pub fn remember_quote(&mut self) {
+        if let Some(quote) = Quote::resolve(&self.quote_code, &self.rates, &self.data) {
+            self.last_quote = Some(quote);
+        }
+    }
+
+    /// Returns the inline style of the cell for `item` and `column`.
+    ///
+    /// The enabled conditional formatting rules for the column come first, followed by the
+    /// selection style from `utils::cell_style`, so a highlighted cell always shows as
+    /// highlighted.
+    pub fn cell_style(&self, item: &CryptoData, column: &str) -> String {
+        let id = item.symbol.as_deref().unwrap_or_default();
+        format!(
+            "{}{}",
+            self.rules.cell_style(item, column),
+            utils::cell_style(id, column, &self.selected_cells),
+        )
+    }
+
+    /// Whether coins are shown as cards: as forced by the `compact` property, or else
+    /// when the window is narrower than `CARD_BREAKPOINT`.
+    pub fn is_compact(&self) -> bool {
+        self.compact.unwrap_or(self.window_width < CARD_BREAKPOINT)
+    }
+
+    /// Returns the highlighted `(symbol, column)` cells, sorted so the order is stable.
+    pub fn selection(&self) -> Vec<(String, String)> {
+        let mut cells: Vec<(String, String)> = self.selected_cells.iter().cloned().collect();
+        cells.sort();
+        cells
+    }
+
+    /// Mirrors the page and page size in the page URL, unless the host turned that off.
+    pub fn write_page_url(&self) {
+        if self.sync_url {
+            pagination::write_url(self.page_size, self.page);
+        }
+    }
+
+    /// Saves the column layout as the user's, unless the host chose the columns.
+    pub fn save_column_layout(&self) {
+        if self.save_columns {
+            columns::save(&self.store, &self.columns);
+        }
+    }
 }
@@ -0,0 +1,165 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Keyboard Shortcuts Module
+//!
+//! This module maps key presses to application messages, so the screener can be used
+//! without a mouse: arrow keys move a cell cursor, Space toggles the highlighted state of
+//! the cell under it, number keys sort by column, and single keys focus the filter,
+//! refresh the data, or open and close overlays.
+//!
+//! ## Key Items
+//!
+//! - `SHORTCUTS`: The key bindings, as listed in the help overlay.
+//! - `shortcut_message`: Translates a `KeyboardEvent.key` value into a `Msg`.
+//! - `works_in_dialog`: Decides which shortcuts still apply while a dialog is open.
+//! - `listen_for_shortcuts`: Registers the document-wide `keydown` listener.
+//! - `is_for_screener`: Decides which screener on the page a key press is meant for.
+
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, KeyboardEvent, Node};
+use yew::html::Scope;
+use yew::NodeRef;
+use super::component::Model;
+use super::message::Msg;
+
+/// Key bindings and the message keys of what they do, in the order shown by the help
+/// overlay. Key names that need translating are message keys too; the rest fall back to
+/// themselves.
+pub const SHORTCUTS: [(&str, &str); 8] = [
+    ("\u{2190} \u{2191} \u{2192} \u{2193}", "help.move"),
+    ("help.space", "help.toggle"),
+    ("1\u{2013}9, 0", "help.sort"),
+    ("/", "help.search"),
+    ("r", "help.refresh"),
+    ("Ctrl/Cmd+C", "help.copy"),
+    ("?", "help.help"),
+    ("Esc", "help.close"),
+];
+
+/// Returns the message for a `KeyboardEvent.key` value, or `None` if the key has no
+/// shortcut.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(matches!(shortcut_message("3"), Some(Msg::SortColumnAt(2))));
+/// ```
+pub fn shortcut_message(key: &str) -> Option<Msg> {
+    let msg = match key {
+        "ArrowLeft" => Msg::MoveCursor(-1, 0),
+        "ArrowRight" => Msg::MoveCursor(1, 0),
+        "ArrowUp" => Msg::MoveCursor(0, -1),
+        "ArrowDown" => Msg::MoveCursor(0, 1),
+        " " => Msg::ToggleCursorSelection,
+        "/" => Msg::FocusSearch,
+        "r" | "R" => Msg::FetchData,
+        "?" => Msg::ToggleHelp,
+        "Escape" => Msg::CloseOverlays,
+        "0" => Msg::SortColumnAt(9),
+        digit => match digit.parse::<usize>() {
+            Ok(n @ 1..=9) => Msg::SortColumnAt(n - 1),
+            _ => return None,
+        },
+    };
+    Some(msg)
+}
+
+/// Returns `true` if a shortcut's message still applies while the About or help dialog is
+/// open. Those dialogs are modal, so only closing them and toggling help get through.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(works_in_dialog(&Msg::CloseOverlays));
+/// assert!(!works_in_dialog(&Msg::MoveCursor(1, 0)));
+/// ```
+pub fn works_in_dialog(msg: &Msg) -> bool {
+    matches!(msg, Msg::CloseOverlays | Msg::ToggleHelp)
+}
+
+/// Returns `true` if a key event is meant for the screener rendered in `root`: focus is
+/// inside that screener, or outside every screener while it is the only one on the page.
+///
+/// Clicking a screener focuses its root, which has `tabindex="-1"`, so with several
+/// screeners on a page the keys go to the one last used.
+pub fn is_for_screener(event: &Event, root: &NodeRef) -> bool {
+    let Some(root) = root.cast::<Element>() else {
+        return false;
+    };
+    let target = event.target().and_then(|t| t.dyn_into::<Node>().ok());
+    if target.as_ref().is_some_and(|t| root.contains(Some(t))) {
+        return true;
+    }
+    let in_other_screener = target
+        .and_then(|t| t.dyn_into::<Element>().ok())
+        .is_some_and(|e| e.closest(".crypto-screener").ok().flatten().is_some());
+    !in_other_screener
+        && root
+            .owner_document()
+            .and_then(|d| d.query_selector_all(".crypto-screener").ok())
+            .is_some_and(|screeners| screeners.length() == 1)
+}
+
+/// Registers a document-wide `keydown` listener that sends the message for each shortcut
+/// and prevents the key's default action (such as scrolling or typing the `/`).
+///
+/// Keys pressed with Ctrl, Cmd or Alt are ignored, and so are all keys except Escape
+/// while a form control has focus, so typing in the filter box and operating buttons with
+/// the keyboard work as usual. Keys meant for another screener on the page are ignored
+/// too (see `is_for_screener`), as are all keys except Escape and `?` while a dialog is
+/// open (see `works_in_dialog`). The listener is removed when the returned
+/// `EventListener` is dropped.
+pub fn listen_for_shortcuts(link: Scope<Model>, root: NodeRef) -> Option<EventListener> {
+    let document = web_sys::window()?.document()?;
+    let options = EventListenerOptions::enable_prevent_default();
+    Some(EventListener::new_with_options(&document, "keydown", options, move |event| {
+        if !is_for_screener(event, &root) {
+            return;
+        }
+        let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+            return;
+        };
+        if event.ctrl_key() || event.meta_key() || event.alt_key() {
+            return;
+        }
+        let key = event.key();
+        let in_control = event
+            .target()
+            .and_then(|t| t.dyn_into::<Element>().ok())
+            .is_some_and(|e| matches!(e.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA" | "BUTTON"));
+        if in_control && key != "Escape" {
+            return;
+        }
+        if let Some(msg) = shortcut_message(&key) {
+            let in_dialog = link.get_component().is_some_and(|model| model.show_about || model.show_help);
+            if in_dialog && !works_in_dialog(&msg) {
+                return;
+            }
+            event.prevent_default();
+            link.send_message(msg);
+        }
+    }))
+}
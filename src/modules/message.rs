@@ -29,7 +29,14 @@
 //! These messages trigger specific actions, such as fetching data, sorting columns, or toggling
 //! UI elements like the "About" modal.
 
+use std::collections::HashMap;
+use super::component::ViewMode;
 use super::cryptodata::CryptoData;
+use super::export::ExportFormat;
+use super::fetch::{fetch_data, fetch_rates, FetchError};
+use super::i18n::Language;
+use super::rules::Rule;
+use super::theme::Theme;
 
 /// Enum representing messages that can modify the application's state.
 ///
@@ -42,20 +49,202 @@ pub enum Msg {
     /// Sets the fetched data to the model or stores an error message if the request fails.
     ///
     /// - `Ok(Vec<CryptoData>)`: Successfully retrieved data.
-    /// - `Err(reqwest::Error)`: An error occurred during the data fetch.
-    SetData(Result<Vec<CryptoData>, reqwest::Error>),
+    /// - `Err(FetchError)`: An error occurred during the data fetch.
+    SetData(Result<Vec<CryptoData>, FetchError>),
 
     /// Sorts the data by the specified column.
     ///
     /// - `String`: The name of the column to sort by (e.g., "price_usd").
     SortBy(String),
 
-    /// Toggles selection state for a specific cell in the data table.
+    /// Sorts the data by a column in a given direction, as asked by the host page.
     ///
-    /// - `String`: A unique identifier for the row (e.g., a cryptocurrency ID).
+    /// - `Option<String>`: The name of the column to sort by, or `None` to clear the sort.
+    /// - `bool`: `true` for ascending order.
+    SetSort(Option<String>, bool),
+
+    /// Toggles selection state for a specific cell in the data table, and moves the
+    /// keyboard cursor to it.
+    ///
+    /// - `String`: The row's key from `history::series_key`, i.e. its ticker id, or its
+    ///   symbol if it has none.
     /// - `String`: The name of the column (e.g., "symbol").
     ToggleCellSelection(String, String),
 
+    /// Toggles selection of every cell in a row, as when clicking a heatmap tile.
+    ///
+    /// - `String`: A unique identifier for the row (e.g., a cryptocurrency ID).
+    ToggleRowSelection(String),
+
+    /// Switches between the table and heatmap views.
+    SetViewMode(ViewMode),
+
+    /// Sets the column that sizes heatmap tiles (e.g., "market_cap_usd").
+    SetHeatmapSize(String),
+
+    /// Sets the percent change column that colours heatmap tiles (e.g., "percent_change_24h").
+    SetHeatmapColor(String),
+
+    /// Records the table viewport's scroll position, re-rendering only when a different
+    /// set of rows comes into view.
+    ///
+    /// - `i32`: The vertical scroll offset in pixels.
+    /// - `i32`: The viewport height in pixels.
+    Scroll(i32, i32),
+
+    /// Switches between scrolling (`None`) and pages of the given size, returning to the first page.
+    SetPageSize(Option<usize>),
+
+    /// Shows the given 0-based page.
+    SetPage(usize),
+
+    /// Shows or hides the column chooser panel.
+    ToggleColumnChooser,
+
+    /// Shows a hidden column or hides a visible one.
+    ///
+    /// - `String`: The key of the column (e.g., "volume24").
+    ToggleColumn(String),
+
+    /// Moves a column to the position of another, as when dropping one header onto another.
+    ///
+    /// - `String`: The key of the column being moved.
+    /// - `String`: The key of the column whose position it takes.
+    MoveColumn(String, String),
+
+    /// Sets the width of a column while its resize handle is dragged. The layout is saved
+    /// by `CommitColumnResize` when the drag ends.
+    ///
+    /// - `String`: The key of the column.
+    /// - `u32`: The new width in pixels.
+    ResizeColumn(String, u32),
+
+    /// Saves the column layout after a resize has finished.
+    CommitColumnResize,
+
+    /// Restores the default column visibility, order and widths.
+    ResetColumns,
+
+    /// Shows or hides the conditional formatting settings panel.
+    ToggleRulesPanel,
+
+    /// Appends a new formatting rule with default settings.
+    AddRule,
+
+    /// Replaces the formatting rule at the given index.
+    ///
+    /// - `usize`: The index of the rule.
+    /// - `Rule`: The edited rule.
+    UpdateRule(usize, Rule),
+
+    /// Removes the formatting rule at the given index.
+    RemoveRule(usize),
+
+    /// Restores the default formatting rules.
+    ResetRules,
+
+    /// Selects the quote currency for prices, volumes and market caps.
+    ///
+    /// - `String`: The currency code, e.g. "EUR" or "BTC".
+    SetQuote(String),
+
+    /// Requests fiat exchange rates from the rates endpoint.
+    FetchRates,
+
+    /// Sets the fiat exchange rates once fetched.
+    ///
+    /// - `Result<HashMap<String, f64>, reqwest::Error>`: Units per US dollar by currency
+    ///   code, or the error that occurred.
+    SetRates(Result<HashMap<String, f64>, reqwest::Error>),
+
+    /// Selects the colour theme.
+    SetTheme(Theme),
+
+    /// Selects the language of the interface and of number formatting.
+    SetLanguage(Language),
+
+    /// Records a new window width after the browser window is resized.
+    ///
+    /// - `i32`: The window's inner width in CSS pixels.
+    Resize(i32),
+
+    /// Moves the keyboard cell cursor, placing it on the first cell if it is not shown.
+    ///
+    /// - `i32`: Columns to move right (negative for left).
+    /// - `i32`: Rows to move down (negative for up).
+    MoveCursor(i32, i32),
+
+    /// Toggles the selection of the cell under the keyboard cursor by sending
+    /// `Msg::ToggleCellSelection`.
+    ToggleCursorSelection,
+
+    /// Sorts by the visible column at the given zero-based position, via `Msg::SortBy`.
+    SortColumnAt(usize),
+
+    /// Moves keyboard focus to the filter input.
+    FocusSearch,
+
+    /// Shows or hides the keyboard shortcut help overlay.
+    ToggleHelp,
+
+    /// Closes the "About" and help overlays.
+    CloseOverlays,
+
+    /// Sets the filter query used to choose which rows are shown.
+    ///
+    /// - `String`: The raw query text (e.g., "btc rsi_14>70").
+    SetFilter(String),
+
+    /// Downloads the visible rows, in the current sort order, in the given format.
+    Export(ExportFormat),
+
+    /// Copies the highlighted cells of the visible rows to the clipboard as TSV.
+    CopySelection,
+
+    /// Displays an error message from an asynchronous operation (e.g., a clipboard write).
+    ReportError(String),
+
     /// Toggles the visibility of the "About" modal.
     ToggleAbout,
 }
+
+impl Msg {
+    /// Fetches tickers from `url` and wraps the outcome in `Msg::SetData`, ready to send
+    /// back to the model.
+    pub async fn load_data(url: &str) -> Msg {
+        Msg::SetData(fetch_data(url).await)
+    }
+
+    /// Fetches exchange rates from `url` and wraps the outcome in `Msg::SetRates`.
+    pub async fn load_rates(url: &str) -> Msg {
+        Msg::SetRates(fetch_rates(url).await)
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crypto_screener_mock::MockServer;
+
+    #[tokio::test]
+    async fn tickers_become_set_data() {
+        let server = MockServer::start().unwrap();
+        match Msg::load_data(&server.url("/api/tickers/?limit=3")).await {
+            Msg::SetData(Ok(data)) => assert_eq!(data.len(), 3),
+            _ => panic!("expected Msg::SetData with three coins"),
+        }
+        match Msg::load_data(&server.url("/rate-limited/api/tickers/")).await {
+            Msg::SetData(Err(FetchError::Request(err))) => assert_eq!(err.status().map(|s| s.as_u16()), Some(429)),
+            _ => panic!("expected Msg::SetData with a request error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rates_become_set_rates() {
+        let server = MockServer::start().unwrap();
+        match Msg::load_rates(&server.url("/v6/latest/USD")).await {
+            Msg::SetRates(Ok(rates)) => assert_eq!(rates.get("GBP"), Some(&0.79)),
+            _ => panic!("expected Msg::SetRates with the recorded rates"),
+        }
+    }
+}
@@ -26,35 +26,85 @@
 //!
 //! This file defines the main structure of the WBTek Crypto Screener application by organizing 
 //! and exposing various modules that handle different aspects of the app's functionality.
+//! Platform-independent logic lives in the `crypto_screener_core` crate; its modules are
+//! re-exported here under the same names, and the modules marked *(core)* below come
+//! from it unchanged.
 //!
 //! ## Module Overview
 //!
 //! - `component`: Contains the primary `Model` component, managing the application’s main state and UI.
+//! - `api`: The JavaScript API for mounting screeners into a host page and working with them.
 //! - `about`: Defines the "About" modal view, providing information about the application and its usage.
 //! - `button`: Implements the `HeaderButton` component, used for sortable table headers.
-//! - `cryptodata`: Defines the `CryptoData` struct, which models cryptocurrency data fetched from an external API.
+//! - `clipboard`: Writes text to the system clipboard and listens for the copy shortcut.
+//! - `cardview`: Renders coins as compact cards with a sort dropdown on narrow screens.
+//! - `chooserview`: Renders the panel for showing and hiding columns.
+//! - `columns`: Re-exports the core column definitions and layout, and persists the user's layout.
+//! - `currency`: Re-exports core quote currency conversion, and finds and saves the chosen currency.
+//! - `cryptodata` *(core)*: Defines the `CryptoData` struct, which models cryptocurrency data fetched from an external API.
 //! - `headview`: Provides functionality for rendering the table header, including sorting interactions.
-//! - `fetch`: Contains functions for fetching data from the cryptocurrency API.
+//! - `export`: Re-exports core CSV and JSON rendering, and downloads the result as a file.
+//! - `fetch`: Contains functions for fetching data from the cryptocurrency API or a snapshot.
+//! - `filter` *(core)*: Parses filter queries and decides which rows are shown.
+//! - `heatview`: Renders the treemap heatmap view as SVG, as an alternative to the table.
+//! - `helpview`: Renders the keyboard shortcut help overlay.
+//! - `history` *(core)*: Records price and volume samples across fetches and applies indicators.
+//! - `i18n`: Re-exports the core message catalogue, and keeps the language switcher's state.
 //! - `implmodel`: Adds additional methods and utilities to the main `Model` component.
+//! - `keyboard`: Maps key presses to messages for cursor navigation and shortcuts.
 //! - `message`: Defines messages (`Msg`) used to manage application state updates and user interactions.
+//! - `pagination`: Re-exports core page arithmetic, and keeps the page in the URL.
+//! - `numformat` *(core)*: Locale-aware number formatting for prices, volumes and percentages.
+//! - `modal`: An accessible modal dialog with a focus trap, used by the About and help overlays.
+//! - `pageview`: Renders the page size selector and page navigation controls.
+//! - `responsive`: Tracks the window width that switches between table and card layouts.
 //! - `rowview`: Handles the rendering of rows within the cryptocurrency data table.
-//! - `sort`: Provides utilities for sorting data based on various criteria.
-//! - `utils`: Contains miscellaneous utility functions used throughout the application.
+//! - `rules`: Re-exports the core conditional formatting rules, and saves them with the view.
+//! - `rulesview`: Renders the settings panel for editing formatting rules.
+//! - `selection` *(core)*: Toggles highlighted cells and rows.
+//! - `snapshot` *(core)*: Reads recorded ticker payloads, including inline `data:` URLs.
+//! - `sort` *(core)*: Provides utilities for sorting data based on various criteria.
+//! - `stats` *(core)*: Computes count, sum, mean, median, min and max of highlighted numeric cells.
+//! - `statsview`: Renders the selection statistics status bar.
+//! - `storage`: Saves and loads JSON configuration in the browser's local storage.
+//! - `theme`: Light, dark and system colour themes, and host-forced themes.
+//! - `treemap` *(core)*: Squarified treemap layout used by the heatmap view.
+//! - `utils`: Provides the style of highlighted cells.
 //!
 //! Each module focuses on a specific part of the application's functionality, promoting organized
 //! and maintainable code structure. Together, they build a comprehensive interface for interacting
 //! with cryptocurrency data in a Yew application.
 
+pub mod api;
 pub mod component;
 
+use crypto_screener_core::{cryptodata, filter, history, numformat, selection, snapshot, sort, stats, treemap};
+
 mod about;
 mod button;
-mod cryptodata;
+mod cardview;
+mod chooserview;
+mod clipboard;
+mod columns;
+mod currency;
 mod headview;
+mod export;
 mod fetch;
+mod heatview;
+mod helpview;
+mod i18n;
 mod implmodel;
+mod keyboard;
 mod message;
+mod pagination;
+mod modal;
+mod pageview;
+mod responsive;
 mod rowview;
-mod sort;
+mod rules;
+mod rulesview;
+mod statsview;
+mod storage;
+mod theme;
 mod utils;
 
@@ -0,0 +1,158 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Modal Dialog Module
+//!
+//! This module defines the `Modal` component, an accessible modal dialog used by the
+//! "About" and keyboard help overlays. The dialog is labelled by its title, moves focus to
+//! its close button when opened, keeps Tab and Shift+Tab cycling inside itself, and returns
+//! focus to the previously focused element when closed.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::{
+    AttrValue, Callback, Children, KeyboardEvent, Properties,
+    function_component, use_effect_with, use_node_ref, use_state, Html, html,
+};
+
+/// Elements that can receive keyboard focus inside the dialog.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// Source of unique ids linking each dialog to its title.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Properties for the `Modal` component.
+#[derive(Properties, PartialEq)]
+pub struct ModalProps {
+    /// Title shown at the top of the dialog, which also labels it for screen readers.
+    pub title: AttrValue,
+
+    /// Accessible name of the close button.
+    #[prop_or(AttrValue::Static("Close"))]
+    pub close_label: AttrValue,
+
+    /// Callback triggered when the close button is activated.
+    pub onclose: Callback<()>,
+
+    /// Content of the dialog, shown below the title.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A Yew function component that renders an accessible modal dialog.
+///
+/// # HTML Structure
+///
+/// A `div.modal` backdrop containing a `div.modal-content` with `role="dialog"`,
+/// `aria-modal="true"` and `aria-labelledby` pointing at the `<h2>` title, plus a close
+/// `<button>` with an accessible name.
+///
+/// # Examples
+///
+/// ```rust
+/// html! {
+///     <Modal title="Keyboard Shortcuts" onclose={ctx.link().callback(|_| Msg::ToggleHelp)}>
+///         <p>{ "..." }</p>
+///     </Modal>
+/// }
+/// ```
+#[function_component(Modal)]
+pub fn modal(props: &ModalProps) -> Html {
+    let dialog_ref = use_node_ref();
+    let close_ref = use_node_ref();
+    let title_id = use_state(|| format!("modal-title-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)));
+
+    {
+        let close_ref = close_ref.clone();
+        use_effect_with((), move |_| {
+            let previous = active_element().and_then(|e| e.dyn_into::<HtmlElement>().ok());
+            if let Some(close) = close_ref.cast::<HtmlElement>() {
+                let _ = close.focus();
+            }
+            move || {
+                if let Some(previous) = previous {
+                    let _ = previous.focus();
+                }
+            }
+        });
+    }
+
+    let onkeydown = {
+        let dialog_ref = dialog_ref.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() != "Tab" {
+                return;
+            }
+            let Some(dialog) = dialog_ref.cast::<Element>() else {
+                return;
+            };
+            let Ok(focusable) = dialog.query_selector_all(FOCUSABLE) else {
+                return;
+            };
+            let item = |index: u32| focusable.item(index).and_then(|n| n.dyn_into::<HtmlElement>().ok());
+            let (Some(first), Some(last)) = (item(0), item(focusable.length().saturating_sub(1))) else {
+                return;
+            };
+            let active = active_element();
+            let wrap_to = if e.shift_key() && active.as_ref() == Some(first.as_ref()) {
+                Some(last)
+            } else if !e.shift_key() && active.as_ref() == Some(last.as_ref()) {
+                Some(first)
+            } else {
+                None
+            };
+            if let Some(target) = wrap_to {
+                e.prevent_default();
+                let _ = target.focus();
+            }
+        })
+    };
+    let onclose = props.onclose.reform(|_| ());
+
+    html! {
+        <div class="modal">
+            <div
+                ref={dialog_ref}
+                class="modal-content"
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby={(*title_id).clone()}
+                onkeydown={onkeydown}
+            >
+                <button ref={close_ref} type="button" class="close" aria-label={props.close_label.clone()} onclick={onclose}>
+                    { "\u{00D7}" } // multiply char
+                </button>
+                <h2 id={(*title_id).clone()}>{ props.title.clone() }</h2>
+                { props.children.clone() }
+            </div>
+        </div>
+    }
+}
+
+/// Returns the element that currently has keyboard focus, if any.
+fn active_element() -> Option<Element> {
+    web_sys::window()?.document()?.active_element()
+}
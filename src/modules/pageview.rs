@@ -0,0 +1,105 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Page View Module
+//!
+//! This module provides the `view_pagination` function, which renders the page size
+//! selector and, when paging is on, the first/previous/next/last buttons and a
+//! "Showing 51–100 of 2,314" summary.
+
+use web_sys::HtmlSelectElement;
+use yew::prelude::{Context, Event, Html, TargetCast, html};
+use super::component::Model;
+use super::i18n::{tr, trf};
+use super::message::Msg;
+use super::numformat::{format_fixed, NumberLocale};
+use super::pagination::{page_count, page_range, PAGE_SIZES};
+
+/// Renders the pagination controls for the filtered rows.
+///
+/// Choosing "All" from the page size selector switches back to a single scrolling table.
+///
+/// # Example
+///
+/// ```rust
+/// let controls = view_pagination(ctx, model);
+/// ```
+pub fn view_pagination(ctx: &Context<Model>, model: &Model) -> Html {
+    let link = ctx.link();
+    let lang = model.language;
+    let locale = &model.number_locale;
+    let on_size = link.callback(|e: Event| {
+        let value = e.target_unchecked_into::<HtmlSelectElement>().value();
+        Msg::SetPageSize(value.parse().ok())
+    });
+    let size_selector = html! {
+        <label>
+            { tr(lang, "page.rows") }
+            { " " }
+            <select onchange={on_size}>
+                <option value="" selected={model.page_size.is_none()}>{ tr(lang, "page.all") }</option>
+                { for PAGE_SIZES.iter().map(|size| html! {
+                    <option value={size.to_string()} selected={model.page_size == Some(*size)}>
+                        { size }
+                    </option>
+                }) }
+            </select>
+        </label>
+    };
+
+    let Some(page_size) = model.page_size else {
+        return html! { <div class="pagination">{ size_selector }</div> };
+    };
+    let total = model.visible_rows().count();
+    let pages = page_count(total, page_size);
+    let page = model.page.min(pages - 1);
+    let range = page_range(page, page_size, total);
+    let summary = if total == 0 {
+        tr(lang, "page.none").to_string()
+    } else {
+        trf(lang, "page.showing", &[
+            &group_thousands(range.start + 1, locale),
+            &group_thousands(range.end, locale),
+            &group_thousands(total, locale),
+        ])
+    };
+    let last = pages - 1;
+
+    html! {
+        <div class="pagination">
+            <button disabled={page == 0} onclick={link.callback(|_| Msg::SetPage(0))}>{ tr(lang, "page.first") }</button>
+            <button disabled={page == 0} onclick={link.callback(move |_| Msg::SetPage(page.saturating_sub(1)))}>{ tr(lang, "page.prev") }</button>
+            <span>{ trf(lang, "page.of", &[&group_thousands(page + 1, locale), &group_thousands(pages, locale)]) }</span>
+            <button disabled={page == last} onclick={link.callback(move |_| Msg::SetPage(page + 1))}>{ tr(lang, "page.next") }</button>
+            <button disabled={page == last} onclick={link.callback(move |_| Msg::SetPage(last))}>{ tr(lang, "page.last") }</button>
+            <span>{ summary }</span>
+            { size_selector }
+        </div>
+    }
+}
+
+/// Formats a count with `locale`'s thousands separators, e.g. `2,314`.
+fn group_thousands(n: usize, locale: &NumberLocale) -> String {
+    format_fixed(n as f64, 0, locale)
+}
@@ -0,0 +1,85 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Pagination Module
+//!
+//! This module mirrors the current page and page size in the page URL
+//! (`?page=3&page_size=50`) so a paged view can be bookmarked or shared. The page
+//! arithmetic is re-exported from `crypto_screener_core::pagination`.
+
+use wasm_bindgen::JsValue;
+use web_sys::{Url, UrlSearchParams};
+
+pub use crypto_screener_core::pagination::*;
+
+/// URL query parameter holding the 1-based page number.
+const PAGE_PARAM: &str = "page";
+
+/// URL query parameter holding the page size; absent when scrolling instead of paging.
+const PAGE_SIZE_PARAM: &str = "page_size";
+
+/// Reads the page size and 0-based page from the current URL.
+///
+/// Returns `(None, 0)` if paging is not requested or the parameters are invalid. Page
+/// sizes not listed in `PAGE_SIZES` are ignored.
+pub fn read_url() -> (Option<usize>, usize) {
+    let params = web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .and_then(|search| UrlSearchParams::new_with_str(&search).ok());
+    let Some(params) = params else {
+        return (None, 0);
+    };
+    let number = |name: &str| params.get(name).and_then(|v| v.parse::<usize>().ok());
+    let page_size = number(PAGE_SIZE_PARAM).filter(|size| PAGE_SIZES.contains(size));
+    let page = number(PAGE_PARAM).unwrap_or(1).saturating_sub(1);
+    (page_size, if page_size.is_some() { page } else { 0 })
+}
+
+/// Replaces the current URL's paging parameters without adding a history entry.
+///
+/// Both parameters are removed when `page_size` is `None`. Other query parameters and
+/// the fragment are preserved.
+pub fn write_url(page_size: Option<usize>, page: usize) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Ok(url) = window.location().href().and_then(|href| Url::new(&href)) else {
+        return;
+    };
+    let params = url.search_params();
+    match page_size {
+        Some(size) => {
+            params.set(PAGE_SIZE_PARAM, &size.to_string());
+            params.set(PAGE_PARAM, &(page + 1).to_string());
+        }
+        None => {
+            params.delete(PAGE_SIZE_PARAM);
+            params.delete(PAGE_PARAM);
+        }
+    }
+    url.set_search(&params.to_string().as_string().unwrap_or_default());
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url.href()));
+    }
+}
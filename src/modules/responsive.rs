@@ -0,0 +1,56 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Responsive Layout Module
+//!
+//! This module tracks the browser window's width so the application can switch from the
+//! data table to the card layout on narrow screens such as phones.
+
+use gloo_events::EventListener;
+use yew::html::Scope;
+use super::component::Model;
+use super::message::Msg;
+
+/// Window widths below this many CSS pixels use the card layout instead of the table.
+pub const CARD_BREAKPOINT: i32 = 700;
+
+/// Returns the window's current inner width in CSS pixels, or `i32::MAX` if unknown, so
+/// the table layout is used by default.
+pub fn window_width() -> i32 {
+    web_sys::window()
+        .and_then(|w| w.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .map(|width| width as i32)
+        .unwrap_or(i32::MAX)
+}
+
+/// Registers a window `resize` listener that sends `Msg::Resize` with the new width.
+///
+/// The listener is removed when the returned `EventListener` is dropped.
+pub fn listen_for_resize(link: Scope<Model>) -> Option<EventListener> {
+    let window = web_sys::window()?;
+    Some(EventListener::new(&window, "resize", move |_| {
+        link.send_message(Msg::Resize(window_width()))
+    }))
+}
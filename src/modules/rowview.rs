@@ -27,24 +27,136 @@
 //! This module provides functionality for rendering rows in the cryptocurrency table,
 //! displaying data for each cryptocurrency in a sortable and selectable format. Each
 //! cell is clickable and allows users to highlight specific cells for tracking.
+//!
+//! When paging is on, only the rows of the current page are rendered. Otherwise, to stay
+//! fast with thousands of coins, only the rows inside (or just outside) the scrolled
+//! viewport are rendered, with spacer rows standing in for the rest. Each row is a
+//! memoized `RowView` component, so toggling one cell re-renders only that row.
 
-use yew::prelude::{Context, Html, html};
+use std::ops::Range;
+use std::rc::Rc;
+use yew::prelude::{Callback, Context, Html, Properties, function_component, html};
+use super::columns::column;
 use super::component::Model;
-use super::message::Msg;
-use super::utils::cell_style;
+use super::cryptodata::CryptoData;
+use super::currency::Quote;
+use super::history;
+use super::numformat::NumberLocale;
+use super::pagination::page_range;
+
+/// Height of every table row in pixels; must match `.data-table tbody tr` in the stylesheet.
+pub const ROW_HEIGHT: i32 = 24;
+
+/// Number of extra rows rendered above and below the viewport to hide gaps while scrolling.
+const OVERSCAN: usize = 10;
+
+/// Properties for the `RowView` component.
+///
+/// All properties are cheap to compare, so Yew skips re-rendering a row whose data,
+/// columns and cell styles are unchanged.
+#[derive(Properties, PartialEq)]
+pub struct RowProps {
+    /// The cryptocurrency shown in this row.
+    pub item: Rc<CryptoData>,
+
+    /// Keys of the visible columns, in display order.
+    pub columns: Rc<Vec<&'static str>>,
+
+    /// Currency that monetary cells are shown in.
+    pub quote: Rc<Quote>,
+
+    /// Number separators of the interface language.
+    pub locale: Rc<NumberLocale>,
+
+    /// Inline style of each cell, aligned with `columns`.
+    pub styles: Vec<String>,
+
+    /// Whether each cell is highlighted, aligned with `columns`; exposed as `aria-selected`.
+    pub selected: Vec<bool>,
+
+    /// One-based position of this row among all filtered rows, counting the header row,
+    /// so screen readers report the true position of virtualized rows.
+    pub row_index: usize,
+
+    /// Key of the column holding the keyboard cursor, if it is on this row.
+    pub cursor: Option<String>,
+
+    /// Key of the column whose cell is the table's single Tab stop, if it is on this row.
+    pub tab_stop: Option<String>,
+
+    /// Callback triggered with `(key, column)` when a cell is clicked, `key` being the
+    /// row's `history::series_key`.
+    pub ontoggle: Callback<(String, String)>,
+}
+
+/// A Yew function component that renders one row of the data table.
+///
+/// Clicking a cell emits `ontoggle` with the row's series key and the cell's column, which
+/// the model turns into a `Msg::ToggleCellSelection`. Cells are grid cells whose
+/// `aria-selected` state mirrors their highlight, so selection is not conveyed by colour
+/// alone. Only the `tab_stop` cell is in the Tab order (a roving `tabindex`); the others
+/// are focused by the arrow keys through the keyboard cursor.
+#[function_component(RowView)]
+pub fn row_view(props: &RowProps) -> Html {
+    let id = history::series_key(&props.item).unwrap_or_default().to_string();
+    html! {
+        <tr aria-rowindex={props.row_index.to_string()}>
+            { for props.columns.iter().zip(props.styles.iter()).zip(props.selected.iter()).map(|((key, style), selected)| {
+                let onclick = props.ontoggle.reform({
+                    let id = id.clone();
+                    let key = key.to_string();
+                    move |_| (id.clone(), key.clone())
+                });
+                html! {
+                    <td
+                        class={(props.cursor.as_deref() == Some(*key)).then_some("cursor")}
+                        style={style.clone()}
+                        role="gridcell"
+                        tabindex={if props.tab_stop.as_deref() == Some(*key) { "0" } else { "-1" }}
+                        aria-selected={selected.to_string()}
+                        onclick={onclick}
+                    >
+                        { column(key).map(|c| (c.value)(&props.item, &props.quote, &props.locale)).unwrap_or_default() }
+                    </td>
+                }
+            }) }
+        </tr>
+    }
+}
+
+/// Returns the range of rows to render for the given scroll position.
+///
+/// The range covers the rows intersecting the viewport plus `OVERSCAN` rows on either
+/// side, clamped to `0..row_count`.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(row_window(0, 240, 1000), 0..20);
+/// ```
+pub fn row_window(scroll_top: i32, viewport_height: i32, row_count: usize) -> Range<usize> {
+    let first = (scroll_top.max(0) / ROW_HEIGHT) as usize;
+    let visible = (viewport_height.max(0) / ROW_HEIGHT) as usize + 1;
+    let start = first.saturating_sub(OVERSCAN).min(row_count);
+    let end = (first + visible + OVERSCAN).min(row_count);
+    start..end
+}
 
 /// Renders the rows of the cryptocurrency data table.
 ///
-/// The `view_rows` function iterates over each entry in `model.data`, creating a `<tr>` 
-/// element with individual `<td>` cells for various cryptocurrency attributes, such as 
-/// symbol, name, price, percent changes, and volume. Each cell includes inline styling
-/// based on its selection state and can be clicked to toggle its selection.
+/// The `view_rows` function takes the entries of `model.data` that pass the active filter
+/// and renders a `RowView` for each one on the current page, or inside the scrolled
+/// viewport when paging is off, with one `<td>` cell
+/// per visible column of the model's column layout (such as symbol, name, price, percent
+/// changes, technical indicators, and volume), in the same order as the header. Rows above
+/// and below the viewport are replaced by a single spacer row each, sized so the scrollbar
+/// still reflects the full table.
 ///
 /// # Parameters
 ///
-/// - `ctx`: The Yew [`Context`] for the `Model` component, allowing messages to be sent
-///   in response to user interactions.
-/// - `model`: A reference to the main application `Model`, which holds data and selection states.
+/// - `ctx`: The Yew [`Context`] for the `Model` component.
+/// - `model`: A reference to the main application `Model`, which holds data, selection
+///   states and the scroll position.
 ///
 /// # Returns
 ///
@@ -53,8 +165,10 @@ use super::utils::cell_style;
 /// # Cell Interactivity
 ///
 /// Each `<td>` cell is rendered with the following features:
-/// - **Dynamic Style**: The cell's style is determined by `cell_style`, which highlights
-///   the cell based on its selection status in `model.selected_cells`.
+/// - **Dynamic Style**: The cell's style comes from `Model::cell_style`, which combines
+///   the enabled conditional formatting rules for its column with the highlight for its
+///   selection status in `model.selected_cells`. The selection style comes last, so it wins.
+/// - **Focus**: The grid has a single Tab stop, the cursor cell (see `RowView`).
 /// - **Click Event**: Clicking a cell sends a `Msg::ToggleCellSelection` message with the
 ///   cell's unique identifier (column name and cryptocurrency symbol), allowing users to
 ///   toggle the cell’s selection state.
@@ -66,64 +180,73 @@ use super::utils::cell_style;
 /// ```rust
 /// let rows = view_rows(ctx, model);
 /// ```
-pub fn view_rows(ctx: &Context<Model>, model: &Model) -> Html {
-    let link = ctx.link();
+pub fn view_rows(_ctx: &Context<Model>, model: &Model) -> Html {
+    let rows = model.page_rows();
+    let window = match model.page_size {
+        Some(_) => 0..rows.len(),
+        None => row_window(model.scroll_top, model.viewport_height, rows.len()),
+    };
+    let columns = Rc::new(model.columns.visible_keys());
+    let quote = Rc::new(model.quote());
+    let locale = Rc::new(model.number_locale.clone());
+    // Rows before this page, plus one for the header row, for `aria-rowindex`.
+    let index_offset = match model.page_size {
+        Some(page_size) => page_range(model.page, page_size, model.visible_rows().count()).start + 2,
+        None => 2,
+    };
+    // The cursor cell is the grid's Tab stop, or the first rendered cell if there is none.
+    let cursor_rendered = model.cursor.as_ref().is_some_and(|(id, _)| {
+        rows[window.clone()].iter().any(|item| history::series_key(item) == Some(id.as_str()))
+    });
+    let top_space = window.start as i32 * ROW_HEIGHT;
+    let bottom_space = (rows.len() - window.end) as i32 * ROW_HEIGHT;
+
     html! {
-        { for model.data.iter().map(|item| {
-            let id = item.symbol.clone().unwrap_or_default();
-            html! {
-                <tr>
-                    <td
-                        style={cell_style(&id, "symbol", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "symbol".to_string())
-                        })}
-                    >{ item.symbol.clone().unwrap_or_default() }</td>
-                    <td
-                        style={cell_style(&id, "name", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "name".to_string())
-                        })}
-                    >{ item.truncated_name() }</td>
-                    <td
-                        style={cell_style(&id, "price_usd", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "price_usd".to_string())
-                        })}
-                    >{ item.formatted_price() }</td>
-                    <td
-                        style={cell_style(&id, "percent_change_1h", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "percent_change_1h".to_string())
-                        })}
-                    >{ item.formatted_percent_change_1h() }</td>
-                    <td
-                        style={cell_style(&id, "percent_change_24h", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "percent_change_24h".to_string())
-                        })}
-                    >{ item.formatted_percent_change_24h() }</td>
-                    <td
-                        style={cell_style(&id, "percent_change_7d", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "percent_change_7d".to_string())
-                        })}
-                    >{ item.formatted_percent_change_7d() }</td>
-                    <td
-                        style={cell_style(&id, "volume24", &model.selected_cells)}
-                        onclick={link.callback({
-                            let id = id.clone();
-                            move |_| Msg::ToggleCellSelection(id.clone(), "volume24".to_string())
-                        })}
-                    >{ item.formatted_volume() }</td>
-                </tr>
-            }
-        }) }
+        <>
+            { view_spacer(top_space, columns.len()) }
+            { for rows[window.clone()].iter().zip(window.start..).map(|(item, index)| {
+                let styles: Vec<String> = columns.iter().map(|key| model.cell_style(item, key)).collect();
+                let id = item.symbol.clone().unwrap_or_default();
+                let selected: Vec<bool> = columns
+                    .iter()
+                    .map(|key| model.selected_cells.contains(&(id.clone(), key.to_string())))
+                    .collect();
+                let cursor = model.cursor.as_ref()
+                    .filter(|(id, _)| history::series_key(item) == Some(id.as_str()))
+                    .map(|(_, key)| key.clone());
+                let tab_stop = if cursor_rendered {
+                    cursor.clone()
+                } else {
+                    columns.first().filter(|_| index == window.start).map(|key| key.to_string())
+                };
+                html! {
+                    <RowView
+                        item={Rc::clone(item)}
+                        columns={Rc::clone(&columns)}
+                        quote={Rc::clone(&quote)}
+                        locale={Rc::clone(&locale)}
+                        styles={styles}
+                        selected={selected}
+                        row_index={index_offset + index}
+                        cursor={cursor}
+                        tab_stop={tab_stop}
+                        ontoggle={model.toggle_cell.clone()}
+                    />
+                }
+            }) }
+            { view_spacer(bottom_space, columns.len()) }
+        </>
+    }
+}
+
+/// Renders an empty row of the given height, or nothing if the height is zero.
+fn view_spacer(height: i32, colspan: usize) -> Html {
+    if height <= 0 {
+        return html! {};
+    }
+    html! {
+        <tr class="spacer" aria-hidden="true" style={format!("height: {}px;", height)}>
+            <td colspan={colspan.to_string()} />
+        </tr>
     }
 }
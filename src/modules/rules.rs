@@ -0,0 +1,47 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Conditional Formatting Rules Module
+//!
+//! This module saves the user's conditional formatting rules in local storage together with
+//! the rest of the view configuration. The rules themselves, and how they style cells, are
+//! re-exported from `crypto_screener_core::rules`; `Model::cell_style` composes them with
+//! the selection style from `utils`.
+
+use super::storage::{self, Store};
+
+pub use crypto_screener_core::rules::*;
+
+/// Local storage key for the saved rules.
+const STORAGE_KEY: &str = "rules";
+
+/// Loads the saved rules from local storage, or the default rules if none are saved.
+pub fn load(store: &Store) -> RuleSet {
+    storage::load(store, STORAGE_KEY).unwrap_or_default()
+}
+
+/// Saves the rules to local storage.
+pub fn save(store: &Store, rules: &RuleSet) {
+    storage::save(store, STORAGE_KEY, rules);
+}
@@ -0,0 +1,149 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Rules View Module
+//!
+//! This module provides the `view_rules` function, which renders the settings panel for
+//! conditional formatting rules. Each rule can be enabled, pointed at a numeric column,
+//! given a kind of format and its parameters, or removed; edits are applied immediately
+//! and saved with the view. Rules compare unconverted values, so the bounds of monetary
+//! columns are labelled as US dollars whatever the quote currency.
+
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::{Callback, Context, Event, Html, TargetCast, html};
+use super::columns::column;
+use super::component::Model;
+use super::cryptodata::NUMERIC_COLUMNS;
+use super::i18n::{tr, Language};
+use super::message::Msg;
+use super::rules::{Format, Rule};
+
+/// Renders the conditional formatting settings panel.
+///
+/// # Example
+///
+/// ```rust
+/// let panel = view_rules(ctx, model);
+/// ```
+pub fn view_rules(ctx: &Context<Model>, model: &Model) -> Html {
+    let link = ctx.link();
+    let lang = model.language;
+    html! {
+        <div class="rules-panel">
+            { for model.rules.rules.iter().enumerate().map(|(index, rule)| {
+                let update = link.callback(move |rule: Rule| Msg::UpdateRule(index, rule));
+                view_rule(lang, rule, update, link.callback(move |_| Msg::RemoveRule(index)))
+            }) }
+            <button onclick={link.callback(|_| Msg::AddRule)}>{ tr(lang, "rules.add") }</button>
+            <button onclick={link.callback(|_| Msg::ResetRules)}>{ tr(lang, "rules.reset") }</button>
+        </div>
+    }
+}
+
+/// Renders the editor row for a single rule. Every edit emits the whole updated rule.
+fn view_rule(lang: Language, rule: &Rule, update: Callback<Rule>, remove: Callback<yew::MouseEvent>) -> Html {
+    let edit = |change: fn(&mut Rule, Event)| {
+        let rule = rule.clone();
+        update.reform(move |e: Event| {
+            let mut rule = rule.clone();
+            change(&mut rule, e);
+            rule
+        })
+    };
+    let usd = column(&rule.column).is_some_and(|c| c.monetary);
+    let hint = if usd { Some(tr(lang, "rules.usd_hint")) } else { None };
+    let unit = if usd { " USD" } else { "" };
+
+    html! {
+        <div class="rule">
+            <input
+                type="checkbox"
+                checked={rule.enabled}
+                onchange={edit(|rule, _| rule.enabled = !rule.enabled)}
+            />
+            <select onchange={edit(|rule, e| rule.column = select_value(e))}>
+                { for NUMERIC_COLUMNS.iter().map(|key| html! {
+                    <option value={*key} selected={rule.column == *key}>
+                        { column(key).map(|c| c.localized(lang)).unwrap_or(key) }
+                    </option>
+                }) }
+            </select>
+            <select onchange={edit(|rule, e| rule.format = Format::for_kind(&select_value(e)))}>
+                { for Format::KINDS.iter().map(|(kind, label)| html! {
+                    <option value={*kind} selected={rule.format.kind() == *kind}>{ tr(lang, label) }</option>
+                }) }
+            </select>
+            { match &rule.format {
+                Format::SignColor => html! {},
+                Format::ColorScale { min, max, color, log } => html! {
+                    <>
+                        { format!(" {} ", tr(lang, "rules.from")) }
+                        <input type="number" step="any" title={hint} value={min.to_string()} onchange={edit(|rule, e| {
+                            if let (Format::ColorScale { min, .. }, Some(v)) = (&mut rule.format, number_value(&e)) { *min = v; }
+                        })} />
+                        { unit }
+                        { format!(" {} ", tr(lang, "rules.to")) }
+                        <input type="number" step="any" title={hint} value={max.to_string()} onchange={edit(|rule, e| {
+                            if let (Format::ColorScale { max, .. }, Some(v)) = (&mut rule.format, number_value(&e)) { *max = v; }
+                        })} />
+                        { unit }
+                        <input type="color" value={color.clone()} onchange={edit(|rule, e| {
+                            if let Format::ColorScale { color, .. } = &mut rule.format { *color = input_value(&e); }
+                        })} />
+                        <label>
+                            <input type="checkbox" checked={*log} onchange={edit(|rule, _| {
+                                if let Format::ColorScale { log, .. } = &mut rule.format { *log = !*log; }
+                            })} />
+                            { tr(lang, "rules.log") }
+                        </label>
+                    </>
+                },
+                Format::BoldAbove { threshold } => html! {
+                    <>
+                        <input type="number" step="any" title={hint} value={threshold.to_string()} onchange={edit(|rule, e| {
+                            if let (Format::BoldAbove { threshold }, Some(v)) = (&mut rule.format, number_value(&e)) { *threshold = v; }
+                        })} />
+                        { unit }
+                    </>
+                },
+            }}
+            <button onclick={remove} aria-label={tr(lang, "rules.remove")}>{ "\u{00D7}" }</button>
+        </div>
+    }
+}
+
+/// Reads the value of the `<select>` element that fired `e`.
+fn select_value(e: Event) -> String {
+    e.target_unchecked_into::<HtmlSelectElement>().value()
+}
+
+/// Reads the value of the `<input>` element that fired `e`.
+fn input_value(e: &Event) -> String {
+    e.target_unchecked_into::<HtmlInputElement>().value()
+}
+
+/// Reads the value of the numeric `<input>` element that fired `e`, if it parses.
+fn number_value(e: &Event) -> Option<f64> {
+    input_value(e).parse().ok()
+}
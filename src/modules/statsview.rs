@@ -0,0 +1,81 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Statistics View Module
+//!
+//! This module provides the `view_stats` function, which renders a status bar summarizing
+//! the highlighted numeric cells of the visible columns, one line per column, like the selection
+//! summary of a spreadsheet.
+
+use yew::prelude::{Html, html};
+use super::component::Model;
+use super::columns::column;
+use super::i18n::trf;
+use super::numformat::{format_significant, NumberLocale};
+use super::stats::selection_stats;
+
+/// Renders the selection statistics status bar.
+///
+/// Only highlighted cells in rows passing the active filter are counted. Returns an empty
+/// fragment when no numeric cell is highlighted.
+///
+/// # Example
+///
+/// ```rust
+/// let status_bar = view_stats(model);
+/// ```
+pub fn view_stats(model: &Model) -> Html {
+    let stats = selection_stats(model.visible_rows(), &model.columns.visible_keys(), &model.selected_cells);
+    if stats.is_empty() {
+        return html! {};
+    }
+    let quote = model.quote();
+    html! {
+        <div class="status-bar">
+            { for stats.iter().map(|(key, s)| {
+                let column = column(key);
+                // Statistics scale linearly, so monetary ones convert like single values.
+                let rate = if column.is_some_and(|c| c.monetary) { quote.rate } else { 1.0 };
+                html! {
+                    <div>
+                        <b>{ column.map(|c| c.title(&quote, model.language)).unwrap_or(key.to_string()) }</b>
+                        { trf(model.language, "stats.summary", &[
+                            &s.count,
+                            &format_stat(s.sum * rate, &model.number_locale),
+                            &format_stat(s.mean * rate, &model.number_locale),
+                            &format_stat(s.median * rate, &model.number_locale),
+                            &format_stat(s.min * rate, &model.number_locale),
+                            &format_stat(s.max * rate, &model.number_locale),
+                        ]) }
+                    </div>
+                }
+            }) }
+        </div>
+    }
+}
+
+/// Formats a statistic with at least four significant digits and `locale` separators.
+fn format_stat(value: f64, locale: &NumberLocale) -> String {
+    format_significant(value, locale)
+}
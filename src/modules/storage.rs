@@ -0,0 +1,94 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Local Storage Module
+//!
+//! This module persists small pieces of user configuration (such as the column layout)
+//! as JSON in the browser's `localStorage`. All failures are treated as "nothing stored",
+//! so the application falls back to its defaults when storage is unavailable or corrupt.
+//! Each screener reads and writes through its `Store`, so screeners sharing a page can
+//! keep their settings apart.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::Storage;
+
+/// Prefix for every key written by the screener, to avoid clashing with the host page.
+const KEY_PREFIX: &str = "wbtek.crypto_screener.";
+
+/// Where a screener keeps its settings in `localStorage`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Store {
+    /// The keys of the stand-alone page.
+    #[default]
+    Shared,
+
+    /// Keys namespaced by a name the host page chose, e.g. `wbtek.crypto_screener.watchlist.columns`.
+    Named(String),
+
+    /// Nothing is loaded or saved, so every visit starts from the defaults.
+    Off,
+}
+
+impl Store {
+    /// Returns the full `localStorage` key for `key`, or `None` if persistence is off.
+    fn item_key(&self, key: &str) -> Option<String> {
+        match self {
+            Store::Shared => Some(format!("{KEY_PREFIX}{key}")),
+            Store::Named(name) => Some(format!("{KEY_PREFIX}{name}.{key}")),
+            Store::Off => None,
+        }
+    }
+}
+
+/// Returns the browser's `localStorage`, if available.
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Loads and deserializes the value stored under `key` in `store`.
+///
+/// Returns `None` if storage is unavailable or off, nothing is stored, or the stored JSON
+/// does not match `T`.
+pub fn load<T: DeserializeOwned>(store: &Store, key: &str) -> Option<T> {
+    let json = local_storage()?.get_item(&store.item_key(key)?).ok()??;
+    serde_json::from_str(&json).ok()
+}
+
+/// Serializes and stores `value` under `key` in `store`, unless `store` is `Store::Off`.
+///
+/// Failures (e.g. storage disabled or full) are logged and otherwise ignored.
+pub fn save<T: Serialize>(store: &Store, key: &str, value: &T) {
+    let Some(item_key) = store.item_key(key) else {
+        return;
+    };
+    let stored = serde_json::to_string(value).ok().and_then(|json| {
+        local_storage()?
+            .set_item(&item_key, &json)
+            .ok()
+    });
+    if stored.is_none() {
+        log::warn!("Could not save '{}' to local storage", key);
+    }
+}
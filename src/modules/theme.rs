@@ -0,0 +1,114 @@
+
+// The MIT License (MIT)
+//
+// Copyright (c) 2024 Greg Slocum, WBTek,
+// a division of WhiteBear Family, Inc.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # Theme Module
+//!
+//! This module manages the colour theme. Colours are defined as CSS custom properties in
+//! `styles.css`; the app switches between them by setting a `data-theme` attribute on its
+//! root element, or leaves it unset to follow the system's `prefers-color-scheme`.
+//!
+//! A host page can force a theme, hiding the switcher, with an attribute on its `<html>`
+//! element, e.g. `<html data-screener-theme="dark">`. It can also override any of the
+//! `--cs-*` custom properties in its own stylesheet.
+
+use serde::{Deserialize, Serialize};
+use super::i18n::{tr, Language};
+use super::storage::{self, Store};
+
+/// Local storage key for the chosen theme.
+const STORAGE_KEY: &str = "theme";
+
+/// Attribute on the host page's `<html>` element that forces a theme.
+const HOST_ATTRIBUTE: &str = "data-screener-theme";
+
+/// A colour theme choice.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Follows the system's `prefers-color-scheme` setting.
+    #[default]
+    System,
+
+    /// Always light.
+    Light,
+
+    /// Always dark.
+    Dark,
+}
+
+impl Theme {
+    /// Every theme, in the order offered by the switcher.
+    pub const ALL: [Theme; 3] = [Theme::System, Theme::Light, Theme::Dark];
+
+    /// Returns the identifier used in storage, attributes and `<option>` values.
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// Returns the label shown in the switcher, in `lang`.
+    pub fn label(self, lang: Language) -> &'static str {
+        match self {
+            Theme::System => tr(lang, "theme.system"),
+            Theme::Light => tr(lang, "theme.light"),
+            Theme::Dark => tr(lang, "theme.dark"),
+        }
+    }
+
+    /// Parses a theme identifier as returned by `name`.
+    pub fn from_name(name: &str) -> Option<Theme> {
+        Theme::ALL.into_iter().find(|theme| theme.name() == name)
+    }
+
+    /// Returns the value of the root element's `data-theme` attribute, or `None` to leave
+    /// it unset and follow the system.
+    pub fn attribute(self) -> Option<&'static str> {
+        match self {
+            Theme::System => None,
+            theme => Some(theme.name()),
+        }
+    }
+}
+
+/// Returns the theme forced by the host page, if any.
+pub fn forced() -> Option<Theme> {
+    web_sys::window()?
+        .document()?
+        .document_element()?
+        .get_attribute(HOST_ATTRIBUTE)
+        .and_then(|name| Theme::from_name(name.trim()))
+}
+
+/// Loads the saved theme, defaulting to following the system.
+pub fn load(store: &Store) -> Theme {
+    storage::load(store, STORAGE_KEY).unwrap_or_default()
+}
+
+/// Saves the chosen theme.
+pub fn save(store: &Store, theme: Theme) {
+    storage::save(store, STORAGE_KEY, &theme);
+}
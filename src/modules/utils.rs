@@ -22,47 +22,14 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-//! # Utility Functions for Cell Selection and Styling
+//! # Utility Functions for Cell Styling
 //!
-//! This module provides helper functions for managing the selection state and styling of
-//! table cells in the WBTek Crypto Screener application. These utilities allow cells to be
-//! toggled as selected or unselected and to apply a specific style when selected.
+//! This module provides the style applied to selected table cells in the WBTek Crypto
+//! Screener application. Toggling selections is handled by
+//! `crypto_screener_core::selection`.
 
 use std::collections::HashSet;
 
-/// Toggles the selection state of a table cell.
-///
-/// Given a unique identifier (`id`) and a column name (`column`), this function either adds
-/// the cell to the set of `selected_cells` if it is not already present, or removes it if
-/// it is currently selected.
-///
-/// # Parameters
-///
-/// - `selected_cells`: A mutable reference to a `HashSet` containing the selected cells, represented
-///   as `(id, column)` pairs.
-/// - `id`: A unique identifier for the row, typically the symbol or another unique value.
-/// - `column`: The column name associated with the cell to toggle.
-///
-/// # Example
-///
-/// ```rust
-/// let mut selected_cells = HashSet::new();
-/// toggle_cell_selection(&mut selected_cells, "BTC".to_string(), "price_usd".to_string());
-/// assert!(selected_cells.contains(&("BTC".to_string(), "price_usd".to_string())));
-/// ```
-pub fn toggle_cell_selection(
-    selected_cells: &mut HashSet<(String, String)>,
-    id: String,
-    column: String,
-) {
-    let cell = (id.clone(), column.clone());
-    if selected_cells.contains(&cell) {
-        selected_cells.remove(&cell);
-    } else {
-        selected_cells.insert(cell.clone());
-    }
-}
-
 /// Generates a CSS style string for a table cell based on its selection state.
 ///
 /// This function checks if a cell, identified by `id` and `column`, is present in `selected_cells`.
@@ -77,8 +44,8 @@ pub fn toggle_cell_selection(
 ///
 /// # Returns
 ///
-/// Returns a `String` containing a CSS style for the cell background color if it is selected,
-/// or an empty string if it is not selected.
+/// Returns a `String` setting the cell background to the theme's `--cs-selection` colour if
+/// it is selected, or an empty string if it is not selected.
 ///
 /// # Example
 ///
@@ -86,11 +53,11 @@ pub fn toggle_cell_selection(
 /// let mut selected_cells = HashSet::new();
 /// selected_cells.insert(("BTC".to_string(), "price_usd".to_string()));
 /// let style = cell_style("BTC", "price_usd", &selected_cells);
-/// assert_eq!(style, "background-color: steelblue;");
+/// assert_eq!(style, "background-color: var(--cs-selection);");
 /// ```
 pub fn cell_style(id: &str, column: &str, selected_cells: &HashSet<(String, String)>) -> String {
     if selected_cells.contains(&(id.to_string(), column.to_string())) {
-        "background-color: steelblue;".to_string()
+        "background-color: var(--cs-selection);".to_string()
     } else {
         "".to_string()
     }